@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Seek, SeekFrom, Write};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use fastvlq::{BufReadVlqExt, ReadVlqExt, WriteVlqExt};
+use rand::Rng;
+
+const COUNT: usize = 100_000;
+
+fn fixture() -> File {
+    let path = std::env::temp_dir().join(format!("fastvlq-bench-{}.bin", std::process::id()));
+    let mut rng = rand::rng();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    for _ in 0..COUNT {
+        // Spread values across every length class
+        let shift = rng.random_range(0..64);
+        writer.write_vu64(rng.random::<u64>() >> shift).unwrap();
+    }
+    writer.flush().unwrap();
+    drop(writer);
+
+    let file = File::open(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    file
+}
+
+fn bench_read_vu64(c: &mut Criterion) {
+    let mut file = fixture();
+    let mut group = c.benchmark_group("BufReader<File>");
+    group.throughput(Throughput::Elements(COUNT as u64));
+
+    group.bench_function("read_vu64", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            let mut reader = BufReader::new(&file);
+            let mut sum = 0u64;
+            for _ in 0..COUNT {
+                sum = sum.wrapping_add(reader.read_vu64().unwrap());
+            }
+            sum
+        })
+    });
+
+    group.bench_function("read_vu64_buffered", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            let mut reader = BufReader::new(&file);
+            let mut sum = 0u64;
+            for _ in 0..COUNT {
+                sum = sum.wrapping_add(reader.read_vu64_buffered().unwrap());
+            }
+            sum
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_read_vu64);
+criterion_main!(benches);
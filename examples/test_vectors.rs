@@ -0,0 +1,5 @@
+//! Print the golden test vectors as JSON.
+
+fn main() {
+    print!("{}", fastvlq::test_vectors::to_json());
+}
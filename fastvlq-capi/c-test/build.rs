@@ -0,0 +1,19 @@
+//! Compiles the C test against the checked-in header, for `tests/c_api.rs` to link.
+
+fn main() {
+    println!("cargo::rerun-if-changed=../include/fastvlq.h");
+    println!("cargo::rerun-if-changed=../tests/c_api.c");
+
+    // Only the test links this, so nothing else that depends on this crate picks it up
+    cc::Build::new()
+        .file("../tests/c_api.c")
+        .include("../include")
+        .std("c99")
+        .warnings_into_errors(true)
+        .cargo_metadata(false)
+        .compile("fastvlq_c_api_test");
+    println!(
+        "cargo::rustc-link-search=native={}",
+        std::env::var("OUT_DIR").unwrap()
+    );
+}
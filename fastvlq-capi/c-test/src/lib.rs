@@ -0,0 +1,3 @@
+//! Nothing but a build script that compiles `tests/c_api.c`.
+//!
+//! As a dev-dependency, it keeps the C compiler out of builds of `fastvlq-capi` itself.
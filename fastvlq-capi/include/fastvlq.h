@@ -0,0 +1,175 @@
+/*
+ * C ABI for fastvlq.
+ *
+ * Every function returns FASTVLQ_OK or a negative error code, and writes its
+ * results through out-pointers.
+ *
+ * Encoders take the capacity of `out` in `*out_len` and write back the encoded
+ * length. If `out` is NULL or too small, nothing is written, `*out_len` holds
+ * the length needed and FASTVLQ_ERROR_BUFFER_TOO_SMALL is returned.
+ *
+ * Decoders read one value from the front of `buf` and write it and the number
+ * of bytes read to `value` and `consumed`. On error neither is touched.
+ */
+
+#ifndef FASTVLQ_H
+#define FASTVLQ_H
+
+/* Generated by cbindgen from src/lib.rs; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Success.
+#define FASTVLQ_OK 0
+
+// A required pointer was null.
+#define FASTVLQ_ERROR_NULL_POINTER -1
+
+// The output buffer is too small; the required length was written back.
+#define FASTVLQ_ERROR_BUFFER_TOO_SMALL -2
+
+// The input ended before the complete encoding.
+#define FASTVLQ_ERROR_UNEXPECTED_EOF -3
+
+// The encoded value does not fit in the target type.
+#define FASTVLQ_ERROR_OVERFLOW -4
+
+// The input is not a valid encoding.
+#define FASTVLQ_ERROR_INVALID -5
+
+// Longest encoding of a 32-bit value, for sizing buffers.
+#define FASTVLQ_MAX_LEN_32 5
+
+// Longest encoding of a 64-bit value, for sizing buffers.
+#define FASTVLQ_MAX_LEN_64 9
+
+// Longest encoding of a 128-bit value, for sizing buffers.
+#define FASTVLQ_MAX_LEN_128 18
+
+// A `u128` split into halves, as C has no portable 128-bit integer.
+typedef struct fastvlq_u128 {
+  uint64_t lo;
+  uint64_t hi;
+} fastvlq_u128;
+
+// An `i128` split into halves in two's complement.
+typedef struct fastvlq_i128 {
+  uint64_t lo;
+  int64_t hi;
+} fastvlq_i128;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Encode a `u32` as a `Vu32`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_u32(uint32_t value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vu32` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_u32(const uint8_t *buf, size_t len, uint32_t *value, size_t *consumed);
+
+// Encode an `i32` as a `Vi32`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_i32(int32_t value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vi32` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_i32(const uint8_t *buf, size_t len, int32_t *value, size_t *consumed);
+
+// Encode a `u64` as a `Vu64`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_u64(uint64_t value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vu64` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_u64(const uint8_t *buf, size_t len, uint64_t *value, size_t *consumed);
+
+// Encode an `i64` as a `Vi64`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_i64(int64_t value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vi64` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_i64(const uint8_t *buf, size_t len, int64_t *value, size_t *consumed);
+
+// Encode a `u128` as a `Vu128`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_u128(struct fastvlq_u128 value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vu128` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_u128(const uint8_t *buf,
+                        size_t len,
+                        struct fastvlq_u128 *value,
+                        size_t *consumed);
+
+// Encode an `i128` as a `Vi128`.
+//
+// # Safety
+//
+// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+// for writes of `*out_len` bytes.
+int fastvlq_encode_i128(struct fastvlq_i128 value, uint8_t *out, size_t *out_len);
+
+// Decode a `Vi128` from the front of `buf`.
+//
+// # Safety
+//
+// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+// be null or valid for writes.
+int fastvlq_decode_i128(const uint8_t *buf,
+                        size_t len,
+                        struct fastvlq_i128 *value,
+                        size_t *consumed);
+
+// A static, NUL-terminated description of an error code.
+const char *fastvlq_error_message(int code);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FASTVLQ_H */
@@ -0,0 +1,361 @@
+//! C ABI for fastvlq.
+//!
+//! Every function returns `FASTVLQ_OK` or a negative error code, and writes its results
+//! through out-pointers. The matching declarations are in `include/fastvlq.h`, generated
+//! by cbindgen from this file.
+//!
+//! Encoders take the capacity of `out` in `*out_len` and write back the encoded length. If
+//! `out` is null or too small, nothing is written, `*out_len` holds the length needed and
+//! `FASTVLQ_ERROR_BUFFER_TOO_SMALL` is returned. Decoders write the value and the number of
+//! bytes read to `value` and `consumed`, and touch neither on error.
+
+use core::ffi::{c_char, c_int};
+
+use fastvlq::{DecodeError, Vi32, Vi64, Vi128, Vu32, Vu64, Vu128};
+
+/// Success.
+pub const FASTVLQ_OK: c_int = 0;
+/// A required pointer was null.
+pub const FASTVLQ_ERROR_NULL_POINTER: c_int = -1;
+/// The output buffer is too small; the required length was written back.
+pub const FASTVLQ_ERROR_BUFFER_TOO_SMALL: c_int = -2;
+/// The input ended before the complete encoding.
+pub const FASTVLQ_ERROR_UNEXPECTED_EOF: c_int = -3;
+/// The encoded value does not fit in the target type.
+pub const FASTVLQ_ERROR_OVERFLOW: c_int = -4;
+/// The input is not a valid encoding.
+pub const FASTVLQ_ERROR_INVALID: c_int = -5;
+
+/// Longest encoding of a 32-bit value, for sizing buffers.
+pub const FASTVLQ_MAX_LEN_32: usize = 5;
+/// Longest encoding of a 64-bit value, for sizing buffers.
+pub const FASTVLQ_MAX_LEN_64: usize = 9;
+/// Longest encoding of a 128-bit value, for sizing buffers.
+pub const FASTVLQ_MAX_LEN_128: usize = 18;
+
+// Spelled out above so cbindgen can write them to the header
+const _: () = assert!(FASTVLQ_MAX_LEN_32 == Vu32::MAX_LEN);
+const _: () = assert!(FASTVLQ_MAX_LEN_64 == Vu64::MAX_LEN);
+const _: () = assert!(FASTVLQ_MAX_LEN_128 == Vu128::MAX_LEN);
+
+/// A `u128` split into halves, as C has no portable 128-bit integer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FastvlqU128 {
+    pub lo: u64,
+    pub hi: u64,
+}
+
+impl From<u128> for FastvlqU128 {
+    fn from(n: u128) -> Self {
+        FastvlqU128 {
+            lo: n as u64,
+            hi: (n >> 64) as u64,
+        }
+    }
+}
+
+impl From<FastvlqU128> for u128 {
+    fn from(n: FastvlqU128) -> Self {
+        (n.hi as u128) << 64 | n.lo as u128
+    }
+}
+
+/// An `i128` split into halves in two's complement.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FastvlqI128 {
+    pub lo: u64,
+    pub hi: i64,
+}
+
+impl From<i128> for FastvlqI128 {
+    fn from(n: i128) -> Self {
+        FastvlqI128 {
+            lo: n as u64,
+            hi: (n >> 64) as i64,
+        }
+    }
+}
+
+impl From<FastvlqI128> for i128 {
+    fn from(n: FastvlqI128) -> Self {
+        (n.hi as i128) << 64 | n.lo as i128
+    }
+}
+
+fn error_code(e: DecodeError) -> c_int {
+    match e {
+        DecodeError::UnexpectedEof => FASTVLQ_ERROR_UNEXPECTED_EOF,
+        DecodeError::Overflow => FASTVLQ_ERROR_OVERFLOW,
+        _ => FASTVLQ_ERROR_INVALID,
+    }
+}
+
+/// Copy an encoding to `out`, or report the length it needs.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+unsafe fn write_encoded(encoded: &[u8], out: *mut u8, out_len: *mut usize) -> c_int {
+    if out_len.is_null() {
+        return FASTVLQ_ERROR_NULL_POINTER;
+    }
+    // SAFETY: checked for null above, and the caller guarantees validity
+    let capacity = unsafe { *out_len };
+    unsafe { *out_len = encoded.len() };
+    if out.is_null() || capacity < encoded.len() {
+        return FASTVLQ_ERROR_BUFFER_TOO_SMALL;
+    }
+    // SAFETY: `out` holds at least `capacity >= encoded.len()` bytes
+    unsafe { core::ptr::copy_nonoverlapping(encoded.as_ptr(), out, encoded.len()) };
+    FASTVLQ_OK
+}
+
+/// Decode the front of `buf` with `decode` and store the value as `C`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+unsafe fn read_decoded<V: Into<T>, T, C: From<T>>(
+    decode: impl FnOnce(&[u8]) -> Result<(V, usize), DecodeError>,
+    buf: *const u8,
+    len: usize,
+    value: *mut C,
+    consumed: *mut usize,
+) -> c_int {
+    if (buf.is_null() && len > 0) || value.is_null() || consumed.is_null() {
+        return FASTVLQ_ERROR_NULL_POINTER;
+    }
+    let buf = match len {
+        0 => &[][..],
+        // SAFETY: non-null, and the caller guarantees `len` readable bytes
+        _ => unsafe { core::slice::from_raw_parts(buf, len) },
+    };
+    match decode(buf) {
+        Ok((n, len)) => {
+            // SAFETY: both checked for null above, and the caller guarantees validity
+            unsafe {
+                *value = C::from(n.into());
+                *consumed = len;
+            }
+            FASTVLQ_OK
+        }
+        Err(e) => error_code(e),
+    }
+}
+
+/// Encode a `u32` as a `Vu32`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_u32(
+    value: u32,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vu32::new(value).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vu32` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_u32(
+    buf: *const u8,
+    len: usize,
+    value: *mut u32,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, u32, _>(Vu32::from_bytes, buf, len, value, consumed) }
+}
+
+/// Encode an `i32` as a `Vi32`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_i32(
+    value: i32,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vi32::new(value).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vi32` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_i32(
+    buf: *const u8,
+    len: usize,
+    value: *mut i32,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, i32, _>(Vi32::from_bytes, buf, len, value, consumed) }
+}
+
+/// Encode a `u64` as a `Vu64`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_u64(
+    value: u64,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vu64::new(value).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vu64` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_u64(
+    buf: *const u8,
+    len: usize,
+    value: *mut u64,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, u64, _>(Vu64::from_bytes, buf, len, value, consumed) }
+}
+
+/// Encode an `i64` as a `Vi64`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_i64(
+    value: i64,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vi64::new(value).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vi64` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_i64(
+    buf: *const u8,
+    len: usize,
+    value: *mut i64,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, i64, _>(Vi64::from_bytes, buf, len, value, consumed) }
+}
+
+/// Encode a `u128` as a `Vu128`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_u128(
+    value: FastvlqU128,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vu128::new(value.into()).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vu128` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_u128(
+    buf: *const u8,
+    len: usize,
+    value: *mut FastvlqU128,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, u128, _>(Vu128::from_bytes, buf, len, value, consumed) }
+}
+
+/// Encode an `i128` as a `Vi128`.
+///
+/// # Safety
+///
+/// `out_len` must be null or valid for reads and writes, and `out` must be null or valid
+/// for writes of `*out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_encode_i128(
+    value: FastvlqI128,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { write_encoded(Vi128::new(value.into()).as_slice(), out, out_len) }
+}
+
+/// Decode a `Vi128` from the front of `buf`.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes, and `value` and `consumed` must
+/// be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fastvlq_decode_i128(
+    buf: *const u8,
+    len: usize,
+    value: *mut FastvlqI128,
+    consumed: *mut usize,
+) -> c_int {
+    // SAFETY: forwarded from the caller
+    unsafe { read_decoded::<_, i128, _>(Vi128::from_bytes, buf, len, value, consumed) }
+}
+
+/// A static, NUL-terminated description of an error code.
+#[unsafe(no_mangle)]
+pub extern "C" fn fastvlq_error_message(code: c_int) -> *const c_char {
+    let message: &'static core::ffi::CStr = match code {
+        FASTVLQ_OK => c"success",
+        FASTVLQ_ERROR_NULL_POINTER => c"null pointer",
+        FASTVLQ_ERROR_BUFFER_TOO_SMALL => c"output buffer too small",
+        FASTVLQ_ERROR_UNEXPECTED_EOF => c"unexpected end of input",
+        FASTVLQ_ERROR_OVERFLOW => c"value overflows the target type",
+        FASTVLQ_ERROR_INVALID => c"invalid encoding",
+        _ => c"unknown error",
+    };
+    message.as_ptr()
+}
@@ -0,0 +1,153 @@
+/* Exercises the C ABI through the checked-in header. Built by c-test/build.rs and run by
+ * tests/c_api.rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "fastvlq.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                   \
+    do {                                                              \
+        if (!(cond)) {                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,    \
+                    __LINE__, #cond);                                 \
+            failures++;                                               \
+        }                                                             \
+    } while (0)
+
+#define ROUND_TRIP(encode, decode, type, v)                           \
+    do {                                                              \
+        uint8_t buf[FASTVLQ_MAX_LEN_128];                             \
+        size_t len = sizeof(buf);                                     \
+        type expected = (v);                                          \
+        type out;                                                     \
+        size_t consumed = 0;                                          \
+        CHECK(encode(expected, buf, &len) == FASTVLQ_OK);             \
+        CHECK(decode(buf, len, &out, &consumed) == FASTVLQ_OK);       \
+        CHECK(consumed == len);                                       \
+        CHECK(memcmp(&out, &expected, sizeof(type)) == 0);            \
+    } while (0)
+
+static void test_vectors(void) {
+    static const struct {
+        uint64_t value;
+        const char *bytes;
+        size_t len;
+    } vectors[] = {
+        {0, "\x80", 1},
+        {127, "\xff", 1},
+        {128, "\x40\x00", 2},
+        {300, "\x40\xac", 2},
+        {UINT64_MAX, "\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7f", 9},
+    };
+    for (size_t i = 0; i < sizeof(vectors) / sizeof(vectors[0]); i++) {
+        uint8_t buf[FASTVLQ_MAX_LEN_64];
+        size_t len = sizeof(buf);
+        CHECK(fastvlq_encode_u64(vectors[i].value, buf, &len) == FASTVLQ_OK);
+        CHECK(len == vectors[i].len);
+        CHECK(memcmp(buf, vectors[i].bytes, len) == 0);
+
+        uint64_t value = 0;
+        size_t consumed = 0;
+        CHECK(fastvlq_decode_u64((const uint8_t *)vectors[i].bytes,
+                                 vectors[i].len, &value,
+                                 &consumed) == FASTVLQ_OK);
+        CHECK(value == vectors[i].value);
+        CHECK(consumed == vectors[i].len);
+    }
+
+    uint8_t buf[FASTVLQ_MAX_LEN_64];
+    size_t len = sizeof(buf);
+    CHECK(fastvlq_encode_i64(-300, buf, &len) == FASTVLQ_OK);
+    CHECK(len == 2 && buf[0] == 0x41 && buf[1] == 0xd7);
+}
+
+static void test_round_trips(void) {
+    ROUND_TRIP(fastvlq_encode_u32, fastvlq_decode_u32, uint32_t, 0);
+    ROUND_TRIP(fastvlq_encode_u32, fastvlq_decode_u32, uint32_t, 16511);
+    ROUND_TRIP(fastvlq_encode_u32, fastvlq_decode_u32, uint32_t, UINT32_MAX);
+    ROUND_TRIP(fastvlq_encode_i32, fastvlq_decode_i32, int32_t, -1);
+    ROUND_TRIP(fastvlq_encode_i32, fastvlq_decode_i32, int32_t, INT32_MIN);
+    ROUND_TRIP(fastvlq_encode_i32, fastvlq_decode_i32, int32_t, INT32_MAX);
+    ROUND_TRIP(fastvlq_encode_u64, fastvlq_decode_u64, uint64_t, 72624976668147839u);
+    ROUND_TRIP(fastvlq_encode_u64, fastvlq_decode_u64, uint64_t, UINT64_MAX);
+    ROUND_TRIP(fastvlq_encode_i64, fastvlq_decode_i64, int64_t, INT64_MIN);
+    ROUND_TRIP(fastvlq_encode_i64, fastvlq_decode_i64, int64_t, INT64_MAX);
+
+    const fastvlq_u128 u128s[] = {
+        {0, 0},
+        {UINT64_MAX, 0},
+        {0, 1},
+        {UINT64_MAX, UINT64_MAX},
+    };
+    for (size_t i = 0; i < sizeof(u128s) / sizeof(u128s[0]); i++) {
+        ROUND_TRIP(fastvlq_encode_u128, fastvlq_decode_u128, fastvlq_u128, u128s[i]);
+    }
+
+    const fastvlq_i128 i128s[] = {
+        {0, 0},
+        {UINT64_MAX, -1},
+        {0, INT64_MIN},
+        {UINT64_MAX, INT64_MAX},
+    };
+    for (size_t i = 0; i < sizeof(i128s) / sizeof(i128s[0]); i++) {
+        ROUND_TRIP(fastvlq_encode_i128, fastvlq_decode_i128, fastvlq_i128, i128s[i]);
+    }
+
+    uint8_t buf[FASTVLQ_MAX_LEN_128];
+    size_t len = sizeof(buf);
+    CHECK(fastvlq_encode_u128((fastvlq_u128){UINT64_MAX, UINT64_MAX}, buf, &len) == FASTVLQ_OK);
+    CHECK(len == FASTVLQ_MAX_LEN_128);
+}
+
+static void test_errors(void) {
+    uint8_t buf[FASTVLQ_MAX_LEN_64];
+    size_t len = 1;
+
+    /* A short buffer reports the length it needs */
+    CHECK(fastvlq_encode_u64(300, buf, &len) == FASTVLQ_ERROR_BUFFER_TOO_SMALL);
+    CHECK(len == 2);
+    len = 0;
+    CHECK(fastvlq_encode_u64(UINT64_MAX, NULL, &len) == FASTVLQ_ERROR_BUFFER_TOO_SMALL);
+    CHECK(len == FASTVLQ_MAX_LEN_64);
+    CHECK(fastvlq_encode_u64(1, buf, NULL) == FASTVLQ_ERROR_NULL_POINTER);
+
+    /* Truncated input leaves the outputs alone */
+    uint64_t value = 42;
+    size_t consumed = 42;
+    CHECK(fastvlq_decode_u64((const uint8_t *)"\x40", 1, &value, &consumed) ==
+          FASTVLQ_ERROR_UNEXPECTED_EOF);
+    CHECK(value == 42 && consumed == 42);
+    CHECK(fastvlq_decode_u64(NULL, 0, &value, &consumed) == FASTVLQ_ERROR_UNEXPECTED_EOF);
+    CHECK(fastvlq_decode_u64(NULL, 1, &value, &consumed) == FASTVLQ_ERROR_NULL_POINTER);
+    CHECK(fastvlq_decode_u64(buf, 1, NULL, &consumed) == FASTVLQ_ERROR_NULL_POINTER);
+
+    /* A value too wide for the type overflows, and a longer form than needed is invalid */
+    static const uint8_t zeros[FASTVLQ_MAX_LEN_128] = {0};
+    uint32_t value32 = 42;
+    CHECK(fastvlq_decode_u32(zeros, FASTVLQ_MAX_LEN_64, &value32, &consumed) ==
+          FASTVLQ_ERROR_OVERFLOW);
+    CHECK(value32 == 42 && consumed == 42);
+    fastvlq_u128 value128 = {42, 42};
+    CHECK(fastvlq_decode_u128(zeros, sizeof(zeros), &value128, &consumed) ==
+          FASTVLQ_ERROR_INVALID);
+    CHECK(value128.lo == 42 && consumed == 42);
+
+    CHECK(strcmp(fastvlq_error_message(FASTVLQ_OK), "success") == 0);
+    CHECK(strcmp(fastvlq_error_message(FASTVLQ_ERROR_UNEXPECTED_EOF),
+                 "unexpected end of input") == 0);
+    CHECK(strcmp(fastvlq_error_message(1), "unknown error") == 0);
+}
+
+int fastvlq_c_api_test(void) {
+    test_vectors();
+    test_round_trips();
+    test_errors();
+    if (failures) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
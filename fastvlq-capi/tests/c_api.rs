@@ -0,0 +1,19 @@
+//! Runs `c_api.c`, compiled against the checked-in header by `fastvlq-capi-c-test`.
+
+use core::ffi::c_int;
+
+// Pull in the library so the C test's calls resolve against it
+use fastvlq_capi as _;
+// Its build script compiles the C test
+use fastvlq_capi_c_test as _;
+
+#[link(name = "fastvlq_c_api_test", kind = "static")]
+unsafe extern "C" {
+    fn fastvlq_c_api_test() -> c_int;
+}
+
+#[test]
+fn c_api() {
+    // SAFETY: the C test takes no arguments and only calls the library
+    assert_eq!(unsafe { fastvlq_c_api_test() }, 0, "C checks failed");
+}
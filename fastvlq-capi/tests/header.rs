@@ -0,0 +1,28 @@
+//! Checks that `include/fastvlq.h` matches what cbindgen generates from the source.
+
+use std::path::Path;
+
+#[test]
+fn header_is_current() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(dir.join("src/lib.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    let path = dir.join("include/fastvlq.h");
+    if std::env::var_os("FASTVLQ_UPDATE_HEADER").is_some() {
+        std::fs::write(&path, &generated).unwrap();
+        return;
+    }
+    let checked_in = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        checked_in == generated,
+        "include/fastvlq.h is stale; rerun this test with FASTVLQ_UPDATE_HEADER=1 to regenerate it"
+    );
+}
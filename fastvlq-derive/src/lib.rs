@@ -0,0 +1,420 @@
+//! Derive macros for fastvlq's `VlqEncode` and `VlqDecode` traits.
+//!
+//! Use them through fastvlq's `derive` feature rather than depending on this crate
+//! directly. Fields are written in declaration order, and enums are written as their
+//! discriminant as a `Vu32` followed by the variant's fields.
+//!
+//! By default each field uses its own type's `VlqEncode`/`VlqDecode` implementation, so
+//! a `u32` is a `Vu32` and an `i64` is a `Vi64`. Integer fields can pick another layout
+//! with a field attribute:
+//!
+//! - `#[vlq(vu32)]`, `#[vlq(vu64)]`, `#[vlq(vu128)]`: unsigned VLQ of that width
+//! - `#[vlq(vi32)]`, `#[vlq(vi64)]`, `#[vlq(vi128)]`: signed VLQ of that width
+//! - `#[vlq(fixed)]`: fixed-width big-endian at the field's own width, up to 64 bits
+//!
+//! Values that do not fit the chosen width fail to encode with `EncodeError::Overflow`,
+//! and to decode with `DecodeError::Overflow`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Expr, ExprLit, Fields, Generics, Ident, Lit, Type,
+    parse_macro_input, parse_quote,
+};
+
+/// Derive `fastvlq::VlqEncode`.
+#[proc_macro_derive(VlqEncode, attributes(vlq))]
+pub fn derive_vlq_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_encode(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `fastvlq::VlqDecode`.
+#[proc_macro_derive(VlqDecode, attributes(vlq))]
+pub fn derive_vlq_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_decode(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How a field is laid out.
+enum Encoding {
+    /// The field type's own `VlqEncode`/`VlqDecode`.
+    Native,
+    /// Converted to the VLQ integer type with the given suffix, such as `vu64`.
+    Int(Ident),
+    /// Fixed-width big-endian at the field type's width.
+    Fixed,
+}
+
+struct Field {
+    /// Name the field is bound to while encoding or decoding.
+    binding: Ident,
+    ty: Type,
+    encoding: Encoding,
+}
+
+struct Variant {
+    ident: Ident,
+    discriminant: u32,
+    fields: Vec<Field>,
+    shape: Shape,
+}
+
+/// Whether fields are constructed by name, by position or not at all.
+enum Shape {
+    Named(Vec<Ident>),
+    Unnamed,
+    Unit,
+}
+
+const INT_KINDS: [&str; 6] = ["vu32", "vu64", "vu128", "vi32", "vi64", "vi128"];
+
+fn parse_encoding(attrs: &[Attribute]) -> syn::Result<Encoding> {
+    let mut encoding = Encoding::Native;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("vlq")) {
+        attr.parse_nested_meta(|meta| {
+            if !matches!(encoding, Encoding::Native) {
+                return Err(meta.error("only one vlq encoding can be given per field"));
+            }
+            if meta.path.is_ident("fixed") {
+                encoding = Encoding::Fixed;
+                return Ok(());
+            }
+            match meta.path.get_ident() {
+                Some(ident) if INT_KINDS.contains(&ident.to_string().as_str()) => {
+                    encoding = Encoding::Int(ident.clone());
+                    Ok(())
+                }
+                _ => {
+                    Err(meta.error("expected one of vu32, vu64, vu128, vi32, vi64, vi128 or fixed"))
+                }
+            }
+        })?;
+    }
+    Ok(encoding)
+}
+
+fn parse_fields(fields: &Fields) -> syn::Result<(Vec<Field>, Shape)> {
+    let parsed = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            Ok(Field {
+                binding: format_ident!("__field{}", i),
+                ty: field.ty.clone(),
+                encoding: parse_encoding(&field.attrs)?,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let shape = match fields {
+        Fields::Named(named) => Shape::Named(
+            named
+                .named
+                .iter()
+                .map(|field| field.ident.clone().unwrap())
+                .collect(),
+        ),
+        Fields::Unnamed(_) => Shape::Unnamed,
+        Fields::Unit => Shape::Unit,
+    };
+    Ok((parsed, shape))
+}
+
+fn parse_discriminant(expr: &Expr) -> syn::Result<u32> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<u32>(),
+        _ => Err(Error::new(
+            expr.span(),
+            "vlq enum discriminants must be integer literals",
+        )),
+    }
+}
+
+fn parse_variants(input: &DeriveInput) -> syn::Result<Vec<Variant>> {
+    let Data::Enum(data) = &input.data else {
+        unreachable!()
+    };
+    let mut next = Some(0u32);
+    data.variants
+        .iter()
+        .map(|variant| {
+            let discriminant = match &variant.discriminant {
+                Some((_, expr)) => parse_discriminant(expr)?,
+                None => next.ok_or_else(|| {
+                    Error::new(variant.span(), "vlq enum discriminant overflows a u32")
+                })?,
+            };
+            next = discriminant.checked_add(1);
+            let (fields, shape) = parse_fields(&variant.fields)?;
+            Ok(Variant {
+                ident: variant.ident.clone(),
+                discriminant,
+                fields,
+                shape,
+            })
+        })
+        .collect()
+}
+
+/// Pattern or constructor for a set of fields, such as `Self { a: __field0 }`.
+fn construct(path: TokenStream2, fields: &[Field], shape: &Shape) -> TokenStream2 {
+    let bindings = fields.iter().map(|field| &field.binding);
+    match shape {
+        Shape::Named(names) => quote!(#path { #(#names: #bindings),* }),
+        Shape::Unnamed => quote!(#path(#(#bindings),*)),
+        Shape::Unit => path,
+    }
+}
+
+/// Add `bound` to every type parameter.
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+fn ensure_data(input: &DeriveInput) -> syn::Result<()> {
+    match &input.data {
+        Data::Union(_) => Err(Error::new(
+            input.ident.span(),
+            "vlq encoding cannot be derived for unions",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Statement writing one bound field, which is a reference.
+fn write_field(field: &Field, is_async: bool) -> TokenStream2 {
+    let binding = &field.binding;
+    let ty = &field.ty;
+    let (ext, await_) = if is_async {
+        (quote!(::fastvlq::AsyncWriteVlqExt), quote!(.await))
+    } else {
+        (quote!(::fastvlq::WriteVlqExt), quote!())
+    };
+    match &field.encoding {
+        Encoding::Native if is_async => {
+            quote!(::fastvlq::VlqEncode::write_vlq_async(#binding, &mut *writer).await?;)
+        }
+        Encoding::Native => quote!(::fastvlq::VlqEncode::write_vlq(#binding, &mut *writer)?;),
+        Encoding::Int(kind) => {
+            let write = format_ident!("write_{}", kind);
+            quote! {
+                #ext::#write(
+                    &mut *writer,
+                    ::core::convert::TryFrom::try_from(*#binding)
+                        .map_err(|_| ::fastvlq::EncodeError::Overflow)?,
+                )#await_?;
+            }
+        }
+        Encoding::Fixed => quote! {
+            #ext::write_u64_with::<::fastvlq::FixedBeN<{ ::core::mem::size_of::<#ty>() }>>(
+                &mut *writer,
+                ::fastvlq::__private::FixedInt::to_bits(*#binding),
+            )#await_?;
+        },
+    }
+}
+
+/// Statement reading one field into its binding.
+fn read_field(field: &Field, is_async: bool) -> TokenStream2 {
+    let binding = &field.binding;
+    let ty = &field.ty;
+    let (ext, await_) = if is_async {
+        (quote!(::fastvlq::AsyncReadVlqExt), quote!(.await))
+    } else {
+        (quote!(::fastvlq::ReadVlqExt), quote!())
+    };
+    let value = match &field.encoding {
+        Encoding::Native if is_async => {
+            quote!(<#ty as ::fastvlq::VlqDecode>::read_vlq_async(&mut *reader).await?)
+        }
+        Encoding::Native => quote!(<#ty as ::fastvlq::VlqDecode>::read_vlq(&mut *reader)?),
+        Encoding::Int(kind) => {
+            let read = format_ident!("read_{}", kind);
+            quote! {
+                ::core::convert::TryFrom::try_from(#ext::#read(&mut *reader)#await_?)
+                    .map_err(|_| ::fastvlq::DecodeError::Overflow)?
+            }
+        }
+        Encoding::Fixed => quote! {
+            ::fastvlq::__private::FixedInt::from_bits(
+                #ext::read_u64_with::<::fastvlq::FixedBeN<{ ::core::mem::size_of::<#ty>() }>>(
+                    &mut *reader,
+                )#await_?,
+            )
+        },
+    };
+    quote!(let #binding: #ty = #value;)
+}
+
+fn encode_body(input: &DeriveInput, is_async: bool) -> syn::Result<TokenStream2> {
+    let write_discriminant = |d: u32| {
+        if is_async {
+            quote!(::fastvlq::AsyncWriteVlqExt::write_vu32(&mut *writer, #d).await?;)
+        } else {
+            quote!(::fastvlq::WriteVlqExt::write_vu32(&mut *writer, #d)?;)
+        }
+    };
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (fields, shape) = parse_fields(&data.fields)?;
+            let pattern = construct(quote!(Self), &fields, &shape);
+            let writes = fields.iter().map(|field| write_field(field, is_async));
+            quote! {
+                let #pattern = self;
+                #(#writes)*
+            }
+        }
+        // Nothing to write, and no value to write it for
+        Data::Enum(data) if data.variants.is_empty() => return Ok(quote!(match *self {})),
+        Data::Enum(_) => {
+            let arms = parse_variants(input)?.into_iter().map(|variant| {
+                let ident = &variant.ident;
+                let pattern = construct(quote!(Self::#ident), &variant.fields, &variant.shape);
+                let discriminant = write_discriminant(variant.discriminant);
+                let writes = variant
+                    .fields
+                    .iter()
+                    .map(|field| write_field(field, is_async));
+                quote! {
+                    #pattern => {
+                        #discriminant
+                        #(#writes)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => unreachable!(),
+    };
+    Ok(quote! {
+        #body
+        ::core::result::Result::Ok(())
+    })
+}
+
+fn decode_body(input: &DeriveInput, is_async: bool) -> syn::Result<TokenStream2> {
+    Ok(match &input.data {
+        Data::Struct(data) => {
+            let (fields, shape) = parse_fields(&data.fields)?;
+            let reads = fields.iter().map(|field| read_field(field, is_async));
+            let value = construct(quote!(Self), &fields, &shape);
+            quote! {
+                #(#reads)*
+                ::core::result::Result::Ok(#value)
+            }
+        }
+        Data::Enum(_) => {
+            let read_discriminant = if is_async {
+                quote!(::fastvlq::AsyncReadVlqExt::read_vu32(&mut *reader).await?)
+            } else {
+                quote!(::fastvlq::ReadVlqExt::read_vu32(&mut *reader)?)
+            };
+            let arms = parse_variants(input)?.into_iter().map(|variant| {
+                let ident = &variant.ident;
+                let discriminant = variant.discriminant;
+                let reads = variant
+                    .fields
+                    .iter()
+                    .map(|field| read_field(field, is_async));
+                let value = construct(quote!(Self::#ident), &variant.fields, &variant.shape);
+                quote! {
+                    #discriminant => {
+                        #(#reads)*
+                        ::core::result::Result::Ok(#value)
+                    }
+                }
+            });
+            quote! {
+                match #read_discriminant {
+                    #(#arms)*
+                    other => ::core::result::Result::Err(
+                        ::fastvlq::DecodeError::InvalidDiscriminant(other).into(),
+                    ),
+                }
+            }
+        }
+        Data::Union(_) => unreachable!(),
+    })
+}
+
+fn expand_encode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    ensure_data(input)?;
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::fastvlq::VlqEncode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = encode_body(input, false)?;
+    let async_fn = if cfg!(feature = "async") {
+        let body = encode_body(input, true)?;
+        quote! {
+            async fn write_vlq_async<__W: ::fastvlq::AsyncWriteVlqExt>(
+                &self,
+                writer: &mut __W,
+            ) -> ::std::io::Result<()> {
+                #body
+            }
+        }
+    } else {
+        quote!()
+    };
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::fastvlq::VlqEncode for #ident #ty_generics #where_clause {
+            fn write_vlq<__W: ::std::io::Write>(&self, writer: &mut __W) -> ::std::io::Result<()> {
+                #body
+            }
+
+            #async_fn
+        }
+    })
+}
+
+fn expand_decode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    ensure_data(input)?;
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::fastvlq::VlqDecode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = decode_body(input, false)?;
+    let async_fn = if cfg!(feature = "async") {
+        let body = decode_body(input, true)?;
+        quote! {
+            async fn read_vlq_async<__R: ::fastvlq::AsyncReadVlqExt>(
+                reader: &mut __R,
+            ) -> ::std::io::Result<Self> {
+                #body
+            }
+        }
+    } else {
+        quote!()
+    };
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::fastvlq::VlqDecode for #ident #ty_generics #where_clause {
+            fn read_vlq<__R: ::std::io::Read>(reader: &mut __R) -> ::std::io::Result<Self> {
+                #body
+            }
+
+            #async_fn
+        }
+    })
+}
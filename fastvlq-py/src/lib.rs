@@ -0,0 +1,194 @@
+//! Python bindings for fastvlq.
+//!
+//! Build with `maturin develop`, or with `cargo build` and copy the resulting library to
+//! `fastvlq.so` (`fastvlq.pyd` on Windows) on the Python path.
+
+use fastvlq::{Vf32, Vf64, Vi32, Vi64, Vi128, VlqDecode, Vu32, Vu64, Vu128, Vu128V2};
+use pyo3::IntoPyObjectExt;
+use pyo3::buffer::{PyBuffer, ReadOnlyCell};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyList};
+
+create_exception!(
+    fastvlq,
+    DecodeError,
+    PyValueError,
+    "The input is not a valid encoding of the requested kind."
+);
+
+/// The value types, named as in the Rust API.
+#[derive(Clone, Copy)]
+enum Kind {
+    Vu32,
+    Vi32,
+    Vu64,
+    Vi64,
+    Vu128,
+    Vi128,
+    Vu128V2,
+    Vf32,
+    Vf64,
+}
+
+impl Kind {
+    fn parse(name: &str) -> PyResult<Kind> {
+        Ok(match name {
+            "vu32" => Kind::Vu32,
+            "vi32" => Kind::Vi32,
+            "vu64" => Kind::Vu64,
+            "vi64" => Kind::Vi64,
+            "vu128" => Kind::Vu128,
+            "vi128" => Kind::Vi128,
+            "vu128_v2" => Kind::Vu128V2,
+            "vf32" => Kind::Vf32,
+            "vf64" => Kind::Vf64,
+            _ => return Err(PyValueError::new_err(format!("unknown kind {name:?}"))),
+        })
+    }
+}
+
+fn decode_value<'py, T>(py: Python<'py>, buf: &[u8]) -> PyResult<(Bound<'py, PyAny>, usize)>
+where
+    T: VlqDecode + IntoPyObject<'py>,
+{
+    let (n, len) = T::decode_vlq(buf).map_err(|e| DecodeError::new_err(e.to_string()))?;
+    Ok((n.into_bound_py_any(py)?, len))
+}
+
+fn decode_one<'py>(
+    py: Python<'py>,
+    buf: &[u8],
+    kind: Kind,
+) -> PyResult<(Bound<'py, PyAny>, usize)> {
+    match kind {
+        Kind::Vu32 => decode_value::<u32>(py, buf),
+        Kind::Vi32 => decode_value::<i32>(py, buf),
+        Kind::Vu64 => decode_value::<u64>(py, buf),
+        Kind::Vi64 => decode_value::<i64>(py, buf),
+        Kind::Vu128 => decode_value::<u128>(py, buf),
+        Kind::Vi128 => decode_value::<i128>(py, buf),
+        Kind::Vu128V2 => {
+            let (n, len) =
+                Vu128V2::from_bytes(buf).map_err(|e| DecodeError::new_err(e.to_string()))?;
+            Ok((n.get().into_bound_py_any(py)?, len))
+        }
+        Kind::Vf32 => decode_value::<f32>(py, buf),
+        Kind::Vf64 => decode_value::<f64>(py, buf),
+    }
+}
+
+/// The bytes of a buffer argument.
+enum Bytes<'a> {
+    /// A C-contiguous buffer, read in place.
+    Cells(&'a [ReadOnlyCell<u8>]),
+    /// Any other buffer, copied out in C order.
+    Copied(Vec<u8>),
+}
+
+impl<'a> Bytes<'a> {
+    fn new(py: Python<'a>, data: &'a PyBuffer<u8>) -> PyResult<Self> {
+        Ok(match data.as_slice(py) {
+            Some(cells) => Bytes::Cells(cells),
+            None => Bytes::Copied(data.to_vec(py)?),
+        })
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Bytes::Cells(cells) => cells.len(),
+            Bytes::Copied(buf) => buf.len(),
+        }
+    }
+
+    /// Decode one `kind` value starting at `offset`.
+    ///
+    /// Python code, possibly on another thread, can write to a buffer while it is read, so
+    /// in-place bytes are never borrowed as a `&[u8]`. Instead the at most `Vu128::MAX_LEN`
+    /// bytes one value can span are copied out first.
+    fn decode_at<'py>(
+        &self,
+        py: Python<'py>,
+        offset: usize,
+        kind: Kind,
+    ) -> PyResult<(Bound<'py, PyAny>, usize)> {
+        match self {
+            Bytes::Cells(cells) => {
+                let cells = &cells[offset..cells.len().min(offset + Vu128::MAX_LEN)];
+                let mut buf = [0; Vu128::MAX_LEN];
+                for (byte, cell) in buf.iter_mut().zip(cells) {
+                    *byte = cell.get();
+                }
+                decode_one(py, &buf[..cells.len()], kind)
+            }
+            Bytes::Copied(buf) => decode_one(py, &buf[offset..], kind),
+        }
+    }
+}
+
+/// Encode `n` as `kind`, such as `"vu64"` or `"vi32"`, returning the bytes.
+#[pyfunction]
+fn encode<'py>(
+    py: Python<'py>,
+    n: &Bound<'py, PyAny>,
+    kind: &str,
+) -> PyResult<Bound<'py, PyBytes>> {
+    Ok(match Kind::parse(kind)? {
+        Kind::Vu32 => PyBytes::new(py, Vu32::new(n.extract()?).as_slice()),
+        Kind::Vi32 => PyBytes::new(py, Vi32::new(n.extract()?).as_slice()),
+        Kind::Vu64 => PyBytes::new(py, Vu64::new(n.extract()?).as_slice()),
+        Kind::Vi64 => PyBytes::new(py, Vi64::new(n.extract()?).as_slice()),
+        Kind::Vu128 => PyBytes::new(py, Vu128::new(n.extract()?).as_slice()),
+        Kind::Vi128 => PyBytes::new(py, Vi128::new(n.extract()?).as_slice()),
+        Kind::Vu128V2 => PyBytes::new(py, Vu128V2::new(n.extract()?).as_slice()),
+        Kind::Vf32 => PyBytes::new(py, Vf32::new(n.extract()?).as_slice()),
+        Kind::Vf64 => PyBytes::new(py, Vf64::new(n.extract()?).as_slice()),
+    })
+}
+
+/// Decode one `kind` value from the front of `data`, returning `(value, consumed)`.
+///
+/// `data` can be any object supporting the buffer protocol over bytes.
+#[pyfunction]
+fn decode<'py>(
+    py: Python<'py>,
+    data: PyBuffer<u8>,
+    kind: &str,
+) -> PyResult<(Bound<'py, PyAny>, usize)> {
+    let kind = Kind::parse(kind)?;
+    Bytes::new(py, &data)?.decode_at(py, 0, kind)
+}
+
+/// Decode `data` as back-to-back `kind` values, returning them as a list.
+///
+/// Raises `DecodeError` naming the offset of the first invalid value.
+#[pyfunction]
+fn decode_all<'py>(
+    py: Python<'py>,
+    data: PyBuffer<u8>,
+    kind: &str,
+) -> PyResult<Bound<'py, PyList>> {
+    let kind = Kind::parse(kind)?;
+    let bytes = Bytes::new(py, &data)?;
+    let mut values = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (value, len) = bytes
+            .decode_at(py, offset, kind)
+            .map_err(|e| DecodeError::new_err(format!("offset {offset}: {}", e.value(py))))?;
+        values.push(value);
+        offset += len;
+    }
+    PyList::new(py, values)
+}
+
+#[pymodule]
+#[pyo3(name = "fastvlq")]
+fn fastvlq_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(encode, m)?)?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_all, m)?)?;
+    m.add("DecodeError", m.py().get_type::<DecodeError>())?;
+    Ok(())
+}
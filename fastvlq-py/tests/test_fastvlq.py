@@ -0,0 +1,71 @@
+"""Smoke tests for the extension module, run with `pytest` after `maturin develop`."""
+
+import array
+
+import pytest
+
+import fastvlq
+
+
+def test_round_trips():
+    for kind, values in [
+        ("vu32", [0, 127, 128, 2**32 - 1]),
+        ("vi32", [-(2**31), -1, 0, 2**31 - 1]),
+        ("vu64", [0, 300, 2**64 - 1]),
+        ("vi64", [-(2**63), -300, 2**63 - 1]),
+        ("vu128", [0, 2**64, 2**128 - 1]),
+        ("vi128", [-(2**127), -1, 2**127 - 1]),
+        ("vu128_v2", [0, 2**64, 2**128 - 1]),
+        ("vf32", [0.0, -1.5, 100.0]),
+        ("vf64", [0.0, 0.1, -1e300]),
+    ]:
+        for value in values:
+            data = fastvlq.encode(value, kind)
+            assert fastvlq.decode(data, kind) == (value, len(data))
+
+
+def test_readme_examples():
+    assert fastvlq.encode(300, "vu64") == b"@\xac"
+    assert fastvlq.decode(b"@\xac", "vu64") == (300, 2)
+    assert fastvlq.decode_all(b"\x81\x41\xd7", "vi64") == [-1, -300]
+
+
+def test_buffer_types():
+    data = b"\x81\x41\xd7"
+    for buf in [
+        bytearray(data),
+        memoryview(data),
+        array.array("B", data),
+        # Sliced views skip the leading byte, and strided ones aren't contiguous
+        memoryview(b"\x00" + data)[1:],
+        memoryview(b"\x81\x00\x41\x00\xd7")[::2],
+    ]:
+        assert fastvlq.decode_all(buf, "vi64") == [-1, -300]
+    assert fastvlq.decode(memoryview(b"\x40\x00\xac")[::2], "vu64") == (300, 2)
+    assert fastvlq.decode_all(b"", "vu64") == []
+
+
+def test_bytearray():
+    data = bytearray(fastvlq.encode(-(2**127), "vi128") + fastvlq.encode(-300, "vi128"))
+    assert fastvlq.decode(data, "vi128") == (-(2**127), 18)
+    assert fastvlq.decode_all(data, "vi128") == [-(2**127), -300]
+
+    # Decoding reads the buffer as it is now, not a copy taken earlier
+    data[:] = b"\x40\xac"
+    assert fastvlq.decode(data, "vu64") == (300, 2)
+    data[1] = 0xad
+    assert fastvlq.decode_all(data, "vu64") == [301]
+    with pytest.raises(fastvlq.DecodeError, match="^offset 0: "):
+        fastvlq.decode_all(data[:1], "vu64")
+
+
+def test_errors():
+    with pytest.raises(fastvlq.DecodeError, match="unexpected end of input"):
+        fastvlq.decode(b"\x40", "vu64")
+    with pytest.raises(fastvlq.DecodeError, match="^offset 1: "):
+        fastvlq.decode_all(b"\x81\x40", "vu64")
+    with pytest.raises(ValueError, match="unknown kind"):
+        fastvlq.decode(b"\x80", "vu16")
+    with pytest.raises(OverflowError):
+        fastvlq.encode(2**32, "vu32")
+    assert issubclass(fastvlq.DecodeError, ValueError)
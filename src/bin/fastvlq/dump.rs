@@ -0,0 +1,218 @@
+//! Annotated walk over a buffer of encoded values.
+
+use std::str::FromStr;
+
+use clap::ValueEnum;
+
+use crate::kind::Kind;
+
+/// What a schema field holds.
+#[derive(Debug, Clone, Copy)]
+pub enum Field {
+    Value(Kind),
+    /// A `Vu64` length followed by that many bytes.
+    Bytes,
+    /// As `Bytes`, holding UTF-8.
+    Str,
+}
+
+impl Field {
+    pub fn name(self) -> String {
+        match self {
+            Field::Value(kind) => kind.name(),
+            Field::Bytes => "bytes".into(),
+            Field::Str => "str".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    field: Field,
+    /// Repeat until the end of the input.
+    repeat: bool,
+}
+
+/// Comma-separated fields making up one record, such as `vu32,vi64,bytes*`.
+///
+/// The record repeats until the input runs out. A trailing `*` on the last field repeats
+/// that field alone instead.
+#[derive(Debug, Clone)]
+pub struct Schema(Vec<Item>);
+
+impl From<Kind> for Schema {
+    fn from(kind: Kind) -> Self {
+        Schema(vec![Item {
+            field: Field::Value(kind),
+            repeat: true,
+        }])
+    }
+}
+
+impl FromStr for Schema {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let names = input.split(',').map(str::trim).collect::<Vec<_>>();
+        let mut items = Vec::with_capacity(names.len());
+        for (i, name) in names.iter().enumerate() {
+            let (name, repeat) = match name.strip_suffix('*') {
+                Some(name) => (name, true),
+                None => (*name, false),
+            };
+            if repeat && i + 1 < names.len() {
+                return Err(format!("only the last field can repeat, not {name:?}"));
+            }
+            let field = match name {
+                "bytes" => Field::Bytes,
+                "str" => Field::Str,
+                name => Field::Value(
+                    Kind::from_str(name, true).map_err(|_| format!("unknown field {name:?}"))?,
+                ),
+            };
+            items.push(Item { field, repeat });
+        }
+        Ok(Schema(items))
+    }
+}
+
+pub enum Status {
+    Ok,
+    NonCanonical,
+    Invalid(String),
+}
+
+/// One field found in the input.
+pub struct Entry {
+    pub offset: usize,
+    pub field: Field,
+    /// The field's bytes, cut short if the input ends inside it.
+    pub bytes: Vec<u8>,
+    /// Bytes of the field taken by its length prefix.
+    pub header_len: usize,
+    /// Leading bits of the field that make up its length prefix.
+    pub prefix: String,
+    pub value: Option<String>,
+    pub status: Status,
+}
+
+fn prefix_bits(bytes: &[u8], count: usize) -> String {
+    (0..count.min(bytes.len() * 8))
+        .map(|i| {
+            if bytes[i / 8] & (0x80 >> (i % 8)) != 0 {
+                '1'
+            } else {
+                '0'
+            }
+        })
+        .collect()
+}
+
+/// Read a value of `kind` at the front of `buf`.
+///
+/// An invalid encoding still consumes the length its prefix claims, so the walk can carry
+/// on past it.
+fn read_value(kind: Kind, buf: &[u8]) -> (usize, String, Result<(String, bool), String>) {
+    match kind.decode(buf) {
+        Ok(decoded) => (
+            decoded.len,
+            prefix_bits(buf, kind.prefix_bits(decoded.len)),
+            Ok((decoded.value, decoded.canonical)),
+        ),
+        Err(e) => {
+            let claimed = kind.claimed_len(buf);
+            let prefix =
+                claimed.map_or(String::new(), |len| prefix_bits(buf, kind.prefix_bits(len)));
+            let len = claimed.filter(|&len| len <= buf.len()).unwrap_or(buf.len());
+            (len, prefix, Err(e.to_string()))
+        }
+    }
+}
+
+fn read_field(field: Field, buf: &[u8], offset: usize) -> Entry {
+    let kind = match field {
+        Field::Value(kind) => kind,
+        Field::Bytes | Field::Str => Kind::Vu64,
+    };
+    let (header_len, prefix, result) = read_value(kind, buf);
+    let mut entry = Entry {
+        offset,
+        field,
+        bytes: buf[..header_len].to_vec(),
+        header_len,
+        prefix,
+        value: None,
+        status: Status::Ok,
+    };
+    let (value, canonical) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            entry.status = Status::Invalid(e);
+            return entry;
+        }
+    };
+    if !canonical {
+        entry.status = Status::NonCanonical;
+    }
+    if let Field::Value(_) = field {
+        entry.value = Some(value);
+        return entry;
+    }
+
+    let rest = &buf[header_len..];
+    let len = match value.parse::<usize>() {
+        Ok(len) if len <= rest.len() => len,
+        _ => {
+            entry.bytes.extend(rest);
+            entry.status = Status::Invalid(format!(
+                "length {value} exceeds the {} bytes left",
+                rest.len()
+            ));
+            return entry;
+        }
+    };
+    let payload = &rest[..len];
+    entry.bytes.extend(payload);
+    match field {
+        Field::Str => match std::str::from_utf8(payload) {
+            Ok(s) => entry.value = Some(s.to_string()),
+            Err(e) => entry.status = Status::Invalid(format!("invalid UTF-8: {e}")),
+        },
+        _ => entry.value = Some(crate::to_hex(payload)),
+    }
+    entry
+}
+
+/// Walk `buf` as records of `schema` until it runs out.
+pub fn dump(buf: &[u8], schema: &Schema) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while offset < buf.len() {
+        for (i, item) in schema.0.iter().enumerate() {
+            if offset == buf.len() {
+                // Running out between records is fine, but not partway through one
+                if i > 0 && !item.repeat {
+                    entries.push(Entry {
+                        offset,
+                        field: item.field,
+                        bytes: Vec::new(),
+                        header_len: 0,
+                        prefix: String::new(),
+                        value: None,
+                        status: Status::Invalid("record ends early".into()),
+                    });
+                }
+                return entries;
+            }
+            loop {
+                let entry = read_field(item.field, &buf[offset..], offset);
+                offset += entry.bytes.len();
+                entries.push(entry);
+                if !item.repeat || offset == buf.len() {
+                    break;
+                }
+            }
+        }
+    }
+    entries
+}
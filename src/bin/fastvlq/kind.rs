@@ -0,0 +1,155 @@
+//! The value types the tool can encode and decode.
+
+use clap::ValueEnum;
+use fastvlq::{DecodeError, VlqDecode, VlqEncode, Vu32, Vu64, Vu128, Vu128V2, encode_vu128_v2};
+
+/// A value decoded from the front of a buffer.
+pub struct Decoded {
+    /// The value in decimal.
+    pub value: String,
+    /// Bytes consumed.
+    pub len: usize,
+    /// Whether re-encoding the value gives back the same bytes.
+    pub canonical: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Kind {
+    Vu32,
+    Vi32,
+    Vu64,
+    Vi64,
+    Vu128,
+    Vi128,
+    Vu128V2,
+    Vf32,
+    Vf64,
+}
+
+fn parse<T: std::str::FromStr>(input: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    input
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid number {input:?}: {e}"))
+}
+
+fn encode<T: VlqEncode>(n: T) -> Vec<u8> {
+    n.encode_vlq().expect("native widths always fit")
+}
+
+fn decode<T: VlqDecode + VlqEncode + ToString>(buf: &[u8]) -> Result<Decoded, DecodeError> {
+    let (n, len) = T::decode_vlq(buf)?;
+    Ok(Decoded {
+        value: n.to_string(),
+        len,
+        canonical: encode(n) == buf[..len],
+    })
+}
+
+impl Kind {
+    pub fn name(self) -> String {
+        self.to_possible_value().unwrap().get_name().to_string()
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(self, Kind::Vi32 | Kind::Vi64 | Kind::Vi128)
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, Kind::Vf32 | Kind::Vf64)
+    }
+
+    /// Encode a decimal number.
+    pub fn encode(self, input: &str) -> Result<Vec<u8>, String> {
+        Ok(match self {
+            Kind::Vu32 => encode(parse::<u32>(input)?),
+            Kind::Vi32 => encode(parse::<i32>(input)?),
+            Kind::Vu64 => encode(parse::<u64>(input)?),
+            Kind::Vi64 => encode(parse::<i64>(input)?),
+            Kind::Vu128 => encode(parse::<u128>(input)?),
+            Kind::Vi128 => encode(parse::<i128>(input)?),
+            Kind::Vu128V2 => encode_vu128_v2(parse(input)?).as_slice().to_vec(),
+            Kind::Vf32 => encode(parse::<f32>(input)?),
+            Kind::Vf64 => encode(parse::<f64>(input)?),
+        })
+    }
+
+    /// Decode one value from the front of `buf`.
+    pub fn decode(self, buf: &[u8]) -> Result<Decoded, DecodeError> {
+        match self {
+            Kind::Vu32 => decode::<u32>(buf),
+            Kind::Vi32 => decode::<i32>(buf),
+            Kind::Vu64 => decode::<u64>(buf),
+            Kind::Vi64 => decode::<i64>(buf),
+            Kind::Vu128 => decode::<u128>(buf),
+            Kind::Vi128 => decode::<i128>(buf),
+            Kind::Vu128V2 => {
+                let (n, len) = Vu128V2::from_bytes(buf)?;
+                Ok(Decoded {
+                    value: n.to_string(),
+                    len,
+                    // The v2 layout has exactly one encoding per value
+                    canonical: true,
+                })
+            }
+            Kind::Vf32 => decode::<f32>(buf),
+            Kind::Vf64 => decode::<f64>(buf),
+        }
+    }
+
+    /// Length of the encoding at the front of `buf`, as given by its prefix.
+    ///
+    /// Returns `None` if `buf` is too short to hold the prefix.
+    pub fn claimed_len(self, buf: &[u8]) -> Option<usize> {
+        let first = *buf.first()?;
+        let len = match self {
+            Kind::Vu32 | Kind::Vi32 | Kind::Vf32 => Vu32::len_from_first_byte(first),
+            Kind::Vu64 | Kind::Vi64 | Kind::Vf64 => Vu64::len_from_first_byte(first),
+            // The second byte only matters when the first has no set bits to go on
+            Kind::Vu128 | Kind::Vi128 if first == 0 => Vu128::len_from_prefix(0, *buf.get(1)?),
+            Kind::Vu128 | Kind::Vi128 => Vu128::len_from_prefix(first, 0),
+            Kind::Vu128V2 if first == 1 => Vu128V2::len_from_prefix(1, *buf.get(1)?),
+            Kind::Vu128V2 => Vu128V2::len_from_prefix(first, 0),
+        };
+        Some(len as usize)
+    }
+
+    /// Number of leading bits of an encoding of `len` bytes that make up its length prefix.
+    pub fn prefix_bits(self, len: usize) -> usize {
+        match (self, len) {
+            (Kind::Vu64 | Kind::Vi64 | Kind::Vf64, 9) => 8,
+            // Both extended forms are flagged in the second byte
+            (Kind::Vu128 | Kind::Vi128, 9..=16) => len,
+            (Kind::Vu128 | Kind::Vi128, _) if len > 16 => 16,
+            (Kind::Vu128V2, 8..=15) => len + 1,
+            (Kind::Vu128V2, 16) => 16,
+            (Kind::Vu128V2, 17) => 8,
+            _ => len,
+        }
+    }
+
+    /// Length of the unsigned wire value `n`, which is zigzag encoded for signed types.
+    ///
+    /// Returns `None` for floats, whose length does not grow with the value.
+    pub fn wire_len(self, n: u128) -> Option<u8> {
+        match self {
+            Kind::Vu32 | Kind::Vi32 => Some(Vu32::encoded_len(n as u32)),
+            Kind::Vu64 | Kind::Vi64 => Some(Vu64::encoded_len(n as u64)),
+            Kind::Vu128 | Kind::Vi128 => Some(Vu128::encoded_len(n)),
+            Kind::Vu128V2 => Some(Vu128V2::encoded_len(n)),
+            Kind::Vf32 | Kind::Vf64 => None,
+        }
+    }
+
+    /// Largest unsigned wire value.
+    pub fn wire_max(self) -> u128 {
+        match self {
+            Kind::Vu32 | Kind::Vi32 | Kind::Vf32 => u32::MAX as u128,
+            Kind::Vu64 | Kind::Vi64 | Kind::Vf64 => u64::MAX as u128,
+            Kind::Vu128 | Kind::Vi128 | Kind::Vu128V2 => u128::MAX,
+        }
+    }
+}
@@ -0,0 +1,473 @@
+//! Command-line tool for encoding, decoding and inspecting fastvlq values.
+
+mod dump;
+mod kind;
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::dump::{Schema, Status};
+use crate::kind::Kind;
+
+#[derive(Parser)]
+#[command(
+    name = "fastvlq",
+    version,
+    about = "Encode, decode and inspect fastvlq values"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encode decimal numbers
+    Encode {
+        /// Value type
+        #[arg(short, long, value_enum, default_value_t = Kind::Vu64)]
+        r#type: Kind,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = EncodeFormat::Hex)]
+        format: EncodeFormat,
+        /// Numbers to encode
+        #[arg(required = true, allow_negative_numbers = true)]
+        numbers: Vec<String>,
+    },
+    /// Decode a sequence of values from hex arguments, or raw bytes from stdin
+    Decode {
+        /// Value type
+        #[arg(short, long, value_enum, default_value_t = Kind::Vu64)]
+        r#type: Kind,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = TextFormat::Text)]
+        format: TextFormat,
+        /// Hex-encoded input; reads raw bytes from stdin if omitted
+        hex: Vec<String>,
+    },
+    /// Report the encoded length of each number, or every length class if none are given
+    Len {
+        /// Value type
+        #[arg(short, long, value_enum, default_value_t = Kind::Vu64)]
+        r#type: Kind,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = TextFormat::Text)]
+        format: TextFormat,
+        /// Numbers to measure
+        #[arg(allow_negative_numbers = true)]
+        numbers: Vec<String>,
+    },
+    /// Annotate every value in a file, flagging invalid and non-canonical encodings
+    Dump {
+        /// Value type
+        #[arg(short, long, value_enum, default_value_t = Kind::Vu64)]
+        r#type: Kind,
+        /// Comma-separated record fields, such as `vu32,vi64,bytes*`
+        ///
+        /// Fields are value types, `bytes` or `str`. Records repeat until the input runs out;
+        /// a trailing `*` repeats only the last field.
+        #[arg(short, long, conflicts_with = "type")]
+        schema: Option<Schema>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = TextFormat::Text)]
+        format: TextFormat,
+        /// File to read; reads stdin if omitted
+        file: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EncodeFormat {
+    /// One line of hex per number
+    Hex,
+    /// Encoded bytes written back to back
+    Raw,
+    /// A JSON array of objects
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TextFormat {
+    /// Plain text
+    Text,
+    /// A JSON array of objects, with values as strings to keep 128-bit precision
+    Json,
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn parse_hex(input: &str) -> Result<Vec<u8>, String> {
+    let digits = input
+        .trim_start_matches("0x")
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect::<Vec<_>>();
+    if digits.len() % 2 != 0 {
+        return Err(format!("odd number of hex digits in {input:?}"));
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = pair.iter().collect::<String>();
+            u8::from_str_radix(&pair, 16).map_err(|_| format!("invalid hex {pair:?} in {input:?}"))
+        })
+        .collect()
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn read_stdin() -> Result<Vec<u8>, String> {
+    let mut buf = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut buf)
+        .map_err(|e| e.to_string())?;
+    Ok(buf)
+}
+
+/// Write `items` as a JSON array, one object per line.
+fn print_json(items: impl IntoIterator<Item = String>) {
+    let items = items.into_iter().collect::<Vec<_>>();
+    println!("[");
+    for (i, item) in items.iter().enumerate() {
+        let comma = if i + 1 < items.len() { "," } else { "" };
+        println!("  {item}{comma}");
+    }
+    println!("]");
+}
+
+fn encode(kind: Kind, format: EncodeFormat, numbers: &[String]) -> Result<(), String> {
+    let encoded = numbers
+        .iter()
+        .map(|n| kind.encode(n))
+        .collect::<Result<Vec<_>, _>>()?;
+    match format {
+        EncodeFormat::Hex => {
+            for bytes in &encoded {
+                println!("{}", to_hex(bytes));
+            }
+        }
+        EncodeFormat::Raw => {
+            let mut stdout = std::io::stdout().lock();
+            for bytes in &encoded {
+                stdout.write_all(bytes).map_err(|e| e.to_string())?;
+            }
+            stdout.flush().map_err(|e| e.to_string())?;
+        }
+        EncodeFormat::Json => print_json(encoded.iter().map(|bytes| {
+            let decoded = kind.decode(bytes).expect("freshly encoded");
+            format!(
+                r#"{{"value": "{}", "len": {}, "hex": "{}"}}"#,
+                decoded.value,
+                decoded.len,
+                to_hex(bytes)
+            )
+        })),
+    }
+    Ok(())
+}
+
+fn decode(kind: Kind, format: TextFormat, hex: &[String]) -> Result<(), String> {
+    let buf = if hex.is_empty() {
+        read_stdin()?
+    } else {
+        let mut buf = Vec::new();
+        for input in hex {
+            buf.extend(parse_hex(input)?);
+        }
+        buf
+    };
+
+    let mut values = Vec::new();
+    let mut offset = 0;
+    let mut error = None;
+    while offset < buf.len() {
+        match kind.decode(&buf[offset..]) {
+            Ok(decoded) => {
+                values.push((offset, decoded.len, decoded.value));
+                offset += decoded.len;
+            }
+            Err(e) => {
+                error = Some(format!("offset {offset}: {e}"));
+                break;
+            }
+        }
+    }
+
+    match format {
+        TextFormat::Text => {
+            for (_, _, value) in &values {
+                println!("{value}");
+            }
+        }
+        TextFormat::Json => print_json(values.iter().map(|(offset, len, value)| {
+            format!(r#"{{"offset": {offset}, "len": {len}, "value": "{value}"}}"#)
+        })),
+    }
+    error.map_or(Ok(()), Err)
+}
+
+/// Last wire value of the run of equal lengths that starts at `lo`.
+fn run_end(kind: Kind, lo: u128) -> u128 {
+    let max = kind.wire_max();
+    let len_of = |n| kind.wire_len(n).unwrap();
+    let len = len_of(lo);
+    // Lengths can fall as values grow (the `Vu128` extended forms alternate with the raw
+    // form), but each run covers at least as many values as the one before it. The first
+    // doubling step past this run is less than twice its size, so it lands in the next
+    // run and the bracket holds exactly one change.
+    let (mut low, mut high) = (lo, None);
+    let mut step = 1u128;
+    while let Some(next) = lo.checked_add(step).filter(|&next| next <= max) {
+        if len_of(next) != len {
+            high = Some(next);
+            break;
+        }
+        low = next;
+        step = match step.checked_mul(2) {
+            Some(step) => step,
+            None => break,
+        };
+    }
+    let mut high = match high {
+        Some(high) => high,
+        None if len_of(max) == len => return max,
+        None => max,
+    };
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if len_of(mid) == len {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Wire value runs `(len, lo, hi)` that share an encoded length, in ascending order.
+///
+/// A length can cover several runs, as in the `Vu128` extended forms.
+fn length_classes(kind: Kind) -> Vec<(u8, u128, u128)> {
+    let mut classes = Vec::new();
+    let mut lo = 0u128;
+    loop {
+        let hi = run_end(kind, lo);
+        classes.push((kind.wire_len(lo).unwrap(), lo, hi));
+        if hi == kind.wire_max() {
+            return classes;
+        }
+        lo = hi + 1;
+    }
+}
+
+/// Signed ranges covered by the zigzag wire values `lo..=hi`, as decimal strings.
+fn signed_ranges(lo: u128, hi: u128) -> Vec<(String, String)> {
+    // Even wire values are non-negative, odd ones negative
+    let first_odd = lo | 1;
+    let last_odd = if hi & 1 == 1 {
+        hi
+    } else {
+        hi.saturating_sub(1)
+    };
+    let negative = (first_odd <= hi && last_odd >= lo).then(|| {
+        (
+            last_odd / 2 + 1,  // largest magnitude
+            first_odd / 2 + 1, // smallest magnitude
+        )
+    });
+    let positive = (lo.div_ceil(2) <= hi / 2).then(|| (lo.div_ceil(2), hi / 2));
+    match (negative, positive) {
+        (Some((neg_min, 1)), Some((0, pos_max))) => {
+            vec![(format!("-{neg_min}"), pos_max.to_string())]
+        }
+        (negative, positive) => negative
+            .map(|(min, max)| (format!("-{min}"), format!("-{max}")))
+            .into_iter()
+            .chain(positive.map(|(min, max)| (min.to_string(), max.to_string())))
+            .collect(),
+    }
+}
+
+fn len(kind: Kind, format: TextFormat, numbers: &[String]) -> Result<(), String> {
+    if !numbers.is_empty() {
+        let lens = numbers
+            .iter()
+            .map(|n| {
+                let bytes = kind.encode(n)?;
+                let decoded = kind.decode(&bytes).expect("freshly encoded");
+                Ok((decoded.value, decoded.len))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        match format {
+            TextFormat::Text => {
+                for (value, len) in &lens {
+                    println!("{value}\t{len}");
+                }
+            }
+            TextFormat::Json => print_json(
+                lens.iter()
+                    .map(|(value, len)| format!(r#"{{"value": "{value}", "len": {len}}}"#)),
+            ),
+        }
+        return Ok(());
+    }
+
+    if kind.is_float() {
+        return Err(format!(
+            "{} has no length classes; pass numbers to measure",
+            kind.name()
+        ));
+    }
+    let classes = length_classes(kind)
+        .into_iter()
+        .map(|(len, lo, hi)| {
+            let ranges = if kind.is_signed() {
+                signed_ranges(lo, hi)
+            } else {
+                vec![(lo.to_string(), hi.to_string())]
+            };
+            (len, ranges)
+        })
+        .collect::<Vec<_>>();
+    match format {
+        TextFormat::Text => {
+            println!("bytes\trange");
+            for (len, ranges) in &classes {
+                let ranges = ranges
+                    .iter()
+                    .map(|(min, max)| format!("{min}..={max}"))
+                    .collect::<Vec<_>>();
+                println!("{len}\t{}", ranges.join(", "));
+            }
+        }
+        TextFormat::Json => print_json(classes.iter().map(|(len, ranges)| {
+            let ranges = ranges
+                .iter()
+                .map(|(min, max)| format!(r#"["{min}", "{max}"]"#))
+                .collect::<Vec<_>>();
+            format!(r#"{{"len": {len}, "ranges": [{}]}}"#, ranges.join(", "))
+        })),
+    }
+    Ok(())
+}
+
+fn dump(schema: &Schema, format: TextFormat, file: Option<&PathBuf>) -> Result<(), String> {
+    let buf = match file {
+        Some(path) => std::fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?,
+        None => read_stdin()?,
+    };
+    let entries = dump::dump(&buf, schema);
+    let status = |status: &Status| match status {
+        Status::Ok => "ok".to_string(),
+        Status::NonCanonical => "non-canonical".to_string(),
+        Status::Invalid(e) => format!("invalid: {e}"),
+    };
+    let hex = |entry: &dump::Entry| {
+        let (header, payload) = entry
+            .bytes
+            .split_at(entry.header_len.min(entry.bytes.len()));
+        if payload.is_empty() {
+            to_hex(header)
+        } else {
+            format!("{} {}", to_hex(header), to_hex(payload))
+        }
+    };
+    match format {
+        TextFormat::Text => {
+            println!("offset\tlen\tfield\tprefix\tbytes\tvalue\tstatus");
+            for entry in &entries {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    entry.offset,
+                    entry.bytes.len(),
+                    entry.field.name(),
+                    entry.prefix,
+                    hex(entry),
+                    match (&entry.value, entry.field) {
+                        (Some(value), dump::Field::Str) => format!("{value:?}"),
+                        (Some(value), _) => value.clone(),
+                        (None, _) => "-".into(),
+                    },
+                    status(&entry.status),
+                );
+            }
+        }
+        TextFormat::Json => print_json(entries.iter().map(|entry| {
+            format!(
+                r#"{{"offset": {}, "len": {}, "field": "{}", "prefix": "{}", "bytes": "{}", "value": {}, "status": {}}}"#,
+                entry.offset,
+                entry.bytes.len(),
+                entry.field.name(),
+                entry.prefix,
+                to_hex(&entry.bytes),
+                entry.value.as_deref().map_or("null".into(), json_string),
+                json_string(&status(&entry.status)),
+            )
+        })),
+    }
+
+    let invalid = entries
+        .iter()
+        .filter(|entry| matches!(entry.status, Status::Invalid(_)))
+        .count();
+    match invalid {
+        0 => Ok(()),
+        1 => Err("1 invalid encoding".into()),
+        n => Err(format!("{n} invalid encodings")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Encode {
+            r#type,
+            format,
+            numbers,
+        } => encode(*r#type, *format, numbers),
+        Command::Decode {
+            r#type,
+            format,
+            hex,
+        } => decode(*r#type, *format, hex),
+        Command::Len {
+            r#type,
+            format,
+            numbers,
+        } => len(*r#type, *format, numbers),
+        Command::Dump {
+            r#type,
+            schema,
+            format,
+            file,
+        } => {
+            let schema = schema.clone().unwrap_or_else(|| Schema::from(*r#type));
+            dump(&schema, *format, file.as_ref())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Length-prefixed byte strings.
+//!
+//! A byte string is encoded as its length in bytes as a `Vu64`, followed by the bytes
+//! themselves. Strings are encoded the same way and must be valid UTF-8.
+
+use crate::error::DecodeError;
+use crate::vu64::{decode_vu64, split_vu64};
+
+/// Check a decoded length prefix against the caller's limit.
+#[inline(always)]
+pub(crate) const fn check_len(len: u64, max_len: usize) -> Result<usize, DecodeError> {
+    if len > max_len as u64 {
+        return Err(DecodeError::LengthLimitExceeded { len, max: max_len });
+    }
+    Ok(len as usize)
+}
+
+/// Decode a length-prefixed byte string from the front of `buf`, borrowing the payload.
+///
+/// Returns the payload and the total number of bytes consumed, including the prefix.
+/// Fails if the prefix is larger than `max_len`.
+pub fn decode_vbytes(buf: &[u8], max_len: usize) -> Result<(&[u8], usize), DecodeError> {
+    let (prefix, offset) = split_vu64(buf)?;
+    let len = check_len(decode_vu64(prefix), max_len)?;
+    let bytes = buf[offset..].get(..len).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((bytes, offset + len))
+}
+
+/// Decode a length-prefixed UTF-8 string from the front of `buf`, borrowing the payload.
+///
+/// Returns the string and the total number of bytes consumed, including the prefix.
+/// Fails if the prefix is larger than `max_len` or the payload is not valid UTF-8.
+pub fn decode_vstr(buf: &[u8], max_len: usize) -> Result<(&str, usize), DecodeError> {
+    let (bytes, consumed) = decode_vbytes(buf, max_len)?;
+    let s = core::str::from_utf8(bytes).map_err(DecodeError::InvalidUtf8)?;
+    Ok((s, consumed))
+}
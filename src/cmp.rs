@@ -0,0 +1,135 @@
+//! Ordering encoded values without decoding them.
+//!
+//! Each length class is offset by the capacity of the classes below it, so for `Vu32`,
+//! `Vu64` and `Vu128V2` a longer encoding is always a larger value, and encodings of the
+//! same length order as their bytes do. `Vu128` breaks this above `offset!(9)`, where the
+//! raw 18-byte form sits below the 9-byte form, so those encodings are decoded instead.
+//!
+//! The signed comparisons rely on the zigzag sign bit being the lowest bit of the last
+//! byte, as every class offset is even.
+//!
+//! All functions compare the encodings at the front of each slice and ignore anything
+//! after them. Only canonical encodings, as produced by `new`, order by value. Empty or
+//! truncated input does not panic, but the result is then unspecified.
+
+use core::cmp::Ordering;
+
+use crate::vu32::decode_len_vu32;
+use crate::vu64::decode_len_vu64;
+use crate::vu128::{decode_len_vu128, decode_vu128, split_vu128};
+use crate::vu128_v2::decode_len_vu128_v2;
+
+/// Compare by length, then by bytes.
+#[inline(always)]
+fn cmp_by_len(a: &[u8], a_len: usize, b: &[u8], b_len: usize) -> Ordering {
+    a_len
+        .cmp(&b_len)
+        .then_with(|| encoding(a, a_len).cmp(encoding(b, b_len)))
+}
+
+/// The first `len` bytes of `buf`, or all of it if it is shorter.
+#[inline(always)]
+fn encoding(buf: &[u8], len: usize) -> &[u8] {
+    buf.get(..len).unwrap_or(buf)
+}
+
+#[inline(always)]
+fn first_two(buf: &[u8]) -> (u8, u8) {
+    (
+        buf.first().copied().unwrap_or(0),
+        buf.get(1).copied().unwrap_or(0),
+    )
+}
+
+/// Turn the ordering of two zigzag encodings into the ordering of their values.
+#[inline(always)]
+fn cmp_zigzag(unsigned: Ordering, a: &[u8], a_len: usize, b: &[u8], b_len: usize) -> Ordering {
+    let negative = |buf: &[u8], len: usize| encoding(buf, len).last().is_some_and(|x| x & 1 == 1);
+    match (negative(a, a_len), negative(b, b_len)) {
+        (false, false) => unsigned,
+        (true, true) => unsigned.reverse(),
+        (a, b) => b.cmp(&a),
+    }
+}
+
+#[inline(always)]
+fn len_vu32(buf: &[u8]) -> usize {
+    buf.first()
+        .map_or(0, |&first| decode_len_vu32(first) as usize)
+}
+
+#[inline(always)]
+fn len_vu64(buf: &[u8]) -> usize {
+    buf.first()
+        .map_or(0, |&first| decode_len_vu64(first) as usize)
+}
+
+#[inline(always)]
+fn len_vu128(buf: &[u8]) -> usize {
+    match buf.first() {
+        None => 0,
+        Some(_) => {
+            let (first, second) = first_two(buf);
+            decode_len_vu128(first, second) as usize
+        }
+    }
+}
+
+#[inline(always)]
+fn len_vu128_v2(buf: &[u8]) -> usize {
+    match buf.first() {
+        None => 0,
+        Some(_) => {
+            let (first, second) = first_two(buf);
+            decode_len_vu128_v2(first, second) as usize
+        }
+    }
+}
+
+/// Compare the `Vu32` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vu32(a: &[u8], b: &[u8]) -> Ordering {
+    cmp_by_len(a, len_vu32(a), b, len_vu32(b))
+}
+
+/// Compare the `Vu64` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vu64(a: &[u8], b: &[u8]) -> Ordering {
+    cmp_by_len(a, len_vu64(a), b, len_vu64(b))
+}
+
+/// Compare the `Vu128` encodings at the front of `a` and `b` by value.
+///
+/// Encodings of up to 8 bytes are compared as `Vu64`s. When both are longer they are
+/// decoded, as their lengths do not follow their values.
+pub fn cmp_encoded_vu128(a: &[u8], b: &[u8]) -> Ordering {
+    let (a_len, b_len) = (len_vu128(a), len_vu128(b));
+    if a_len < 9 || b_len < 9 {
+        return cmp_by_len(a, a_len, b, b_len);
+    }
+    match (split_vu128(a), split_vu128(b)) {
+        (Some((a, _)), Some((b, _))) => decode_vu128(a).cmp(&decode_vu128(b)),
+        _ => encoding(a, a_len).cmp(encoding(b, b_len)),
+    }
+}
+
+/// Compare the `Vu128V2` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vu128_v2(a: &[u8], b: &[u8]) -> Ordering {
+    cmp_by_len(a, len_vu128_v2(a), b, len_vu128_v2(b))
+}
+
+/// Compare the `Vi32` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vi32(a: &[u8], b: &[u8]) -> Ordering {
+    let (a_len, b_len) = (len_vu32(a), len_vu32(b));
+    cmp_zigzag(cmp_by_len(a, a_len, b, b_len), a, a_len, b, b_len)
+}
+
+/// Compare the `Vi64` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vi64(a: &[u8], b: &[u8]) -> Ordering {
+    let (a_len, b_len) = (len_vu64(a), len_vu64(b));
+    cmp_zigzag(cmp_by_len(a, a_len, b, b_len), a, a_len, b, b_len)
+}
+
+/// Compare the `Vi128` encodings at the front of `a` and `b` by value.
+pub fn cmp_encoded_vi128(a: &[u8], b: &[u8]) -> Ordering {
+    let (a_len, b_len) = (len_vu128(a), len_vu128(b));
+    cmp_zigzag(cmp_encoded_vu128(a, b), a, a_len, b, b_len)
+}
@@ -0,0 +1,332 @@
+//! Pluggable integer codecs.
+//!
+//! [`IntCodec`] abstracts over how a `u64` is laid out, so serializers can be written
+//! once and run against any encoding by changing a type parameter. The marker types
+//! here cover the native encodings, fixed-width big- and little-endian, and LEB128.
+
+use crate::error::{DecodeError, EncodeError};
+use crate::leb128::{decode_uleb128, encode_uleb128};
+use crate::vu32::{Vu32, decode_len_vu32, decode_vu32, encode_vu32, split_vu32};
+use crate::vu64::{Vu64, decode_len_vu64, decode_vu64, encode_vu64, split_vu64};
+use crate::vu128::{Vu128, decode_len_vu128, decode_vu128, encode_vu128, split_vu128};
+use crate::vu128_v2::{VU128_V2_BUF_SIZE, Vu128V2, decode_len_vu128_v2, encode_vu128_v2};
+
+/// Upper bound on [`IntCodec::max_len`] for codecs used with the reader and writer
+/// extension traits, which encode and decode through a stack buffer of this size.
+pub const MAX_CODEC_LEN: usize = 32;
+
+/// An encoding for `u64` values.
+pub trait IntCodec {
+    /// Encode `n` into the front of `buf`, returning the number of bytes written.
+    ///
+    /// Fails with [`EncodeError::Overflow`] if the codec cannot represent `n`. Panics if
+    /// `buf` is shorter than [`IntCodec::encoded_len`].
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError>;
+
+    /// Decode a value from the front of `buf`.
+    ///
+    /// Returns the value and the number of bytes consumed.
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError>;
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    fn encoded_len(n: u64) -> usize;
+
+    /// Maximum length of an encoded value in bytes.
+    fn max_len() -> usize;
+
+    /// Total length of the encoding that starts with `prefix`, or at least one byte more
+    /// than `prefix` if the length is not yet known.
+    ///
+    /// Readers call this with a growing prefix, starting from a single byte, until the
+    /// length stops changing.
+    fn len_from_prefix(prefix: &[u8]) -> usize;
+}
+
+/// The native [`Vu32`] encoding, restricted to values up to `u32::MAX`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Vu32Codec;
+
+/// The native [`Vu64`] encoding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Vu64Codec;
+
+/// The native [`Vu128`] encoding, restricted to `u64` values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Vu128Codec;
+
+/// The native [`Vu128V2`] encoding, restricted to `u64` values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Vu128V2Codec;
+
+/// Fixed-width 8-byte big-endian.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedBe;
+
+/// Fixed-width 8-byte little-endian.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedLe;
+
+/// Fixed-width big-endian, `N` bytes wide.
+///
+/// `N` must be between 1 and 8. Values that do not fit in `N` bytes fail to encode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedBeN<const N: usize>;
+
+/// Fixed-width little-endian, `N` bytes wide.
+///
+/// `N` must be between 1 and 8. Values that do not fit in `N` bytes fail to encode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedLeN<const N: usize>;
+
+/// Unsigned LEB128, as in the [`leb128`](crate::leb128) module.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Leb128Codec;
+
+impl IntCodec for Vu32Codec {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let n = u32::try_from(n).map_err(|_| EncodeError::Overflow)?;
+        let vlq = encode_vu32(n);
+        buf[..vlq.as_slice().len()].copy_from_slice(vlq.as_slice());
+        Ok(vlq.len() as usize)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let (vlq, len) = split_vu32(buf)?;
+        Ok((decode_vu32(vlq) as u64, len))
+    }
+
+    fn encoded_len(n: u64) -> usize {
+        // Values past `u32::MAX` fail to encode; report them as the longest form
+        Vu32::encoded_len(n.min(u32::MAX as u64) as u32) as usize
+    }
+
+    fn max_len() -> usize {
+        Vu32::MAX_LEN
+    }
+
+    fn len_from_prefix(prefix: &[u8]) -> usize {
+        decode_len_vu32(prefix[0]) as usize
+    }
+}
+
+impl IntCodec for Vu64Codec {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let vlq = encode_vu64(n);
+        buf[..vlq.as_slice().len()].copy_from_slice(vlq.as_slice());
+        Ok(vlq.len() as usize)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let (vlq, len) = split_vu64(buf)?;
+        Ok((decode_vu64(vlq), len))
+    }
+
+    fn encoded_len(n: u64) -> usize {
+        Vu64::encoded_len(n) as usize
+    }
+
+    fn max_len() -> usize {
+        Vu64::MAX_LEN
+    }
+
+    fn len_from_prefix(prefix: &[u8]) -> usize {
+        decode_len_vu64(prefix[0]) as usize
+    }
+}
+
+impl IntCodec for Vu128Codec {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let vlq = encode_vu128(n as u128);
+        buf[..vlq.as_slice().len()].copy_from_slice(vlq.as_slice());
+        Ok(vlq.len() as usize)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let (vlq, len) = split_vu128(buf).ok_or(DecodeError::UnexpectedEof)?;
+        let n = u64::try_from(decode_vu128(vlq)).map_err(|_| DecodeError::Overflow)?;
+        Ok((n, len))
+    }
+
+    fn encoded_len(n: u64) -> usize {
+        Vu128::encoded_len(n as u128) as usize
+    }
+
+    fn max_len() -> usize {
+        Vu128::MAX_LEN
+    }
+
+    fn len_from_prefix(prefix: &[u8]) -> usize {
+        match prefix {
+            [0] => 2,
+            _ => decode_len_vu128(prefix[0], prefix.get(1).copied().unwrap_or(0)) as usize,
+        }
+    }
+}
+
+impl IntCodec for Vu128V2Codec {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let vlq = encode_vu128_v2(n as u128);
+        buf[..vlq.as_slice().len()].copy_from_slice(vlq.as_slice());
+        Ok(vlq.len() as usize)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let first = *buf.first().ok_or(DecodeError::UnexpectedEof)?;
+        let second = if first == 1 {
+            *buf.get(1).ok_or(DecodeError::UnexpectedEof)?
+        } else {
+            0
+        };
+        let len = decode_len_vu128_v2(first, second) as usize;
+        let mut out_buf = [0u8; VU128_V2_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        let vlq = Vu128V2(out_buf);
+        vlq.validate()?;
+        let n = u64::try_from(vlq.get()).map_err(|_| DecodeError::Overflow)?;
+        Ok((n, len))
+    }
+
+    fn encoded_len(n: u64) -> usize {
+        Vu128V2::encoded_len(n as u128) as usize
+    }
+
+    fn max_len() -> usize {
+        Vu128V2::MAX_LEN
+    }
+
+    fn len_from_prefix(prefix: &[u8]) -> usize {
+        match prefix {
+            [1] => 2,
+            _ => decode_len_vu128_v2(prefix[0], prefix.get(1).copied().unwrap_or(0)) as usize,
+        }
+    }
+}
+
+/// Reject values that need more than `N` bytes.
+#[inline(always)]
+const fn check_fixed<const N: usize>(n: u64) -> Result<u64, EncodeError> {
+    const {
+        assert!(
+            N >= 1 && N <= 8,
+            "fixed width must be between 1 and 8 bytes"
+        )
+    };
+    if N < 8 && n >> (8 * N) != 0 {
+        return Err(EncodeError::Overflow);
+    }
+    Ok(n)
+}
+
+impl<const N: usize> IntCodec for FixedBeN<N> {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let bytes = check_fixed::<N>(n)?.to_be_bytes();
+        buf[..N].copy_from_slice(&bytes[8 - N..]);
+        Ok(N)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let mut bytes = [0u8; 8];
+        bytes[8 - N..].copy_from_slice(buf.get(..N).ok_or(DecodeError::UnexpectedEof)?);
+        Ok((u64::from_be_bytes(bytes), N))
+    }
+
+    fn encoded_len(_n: u64) -> usize {
+        N
+    }
+
+    fn max_len() -> usize {
+        N
+    }
+
+    fn len_from_prefix(_prefix: &[u8]) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> IntCodec for FixedLeN<N> {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let bytes = check_fixed::<N>(n)?.to_le_bytes();
+        buf[..N].copy_from_slice(&bytes[..N]);
+        Ok(N)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let mut bytes = [0u8; 8];
+        bytes[..N].copy_from_slice(buf.get(..N).ok_or(DecodeError::UnexpectedEof)?);
+        Ok((u64::from_le_bytes(bytes), N))
+    }
+
+    fn encoded_len(_n: u64) -> usize {
+        N
+    }
+
+    fn max_len() -> usize {
+        N
+    }
+
+    fn len_from_prefix(_prefix: &[u8]) -> usize {
+        N
+    }
+}
+
+/// Forward every method to another codec with the same layout.
+macro_rules! impl_int_codec_as {
+    ($($codec:ty => $inner:ty;)*) => {
+        $(
+            impl IntCodec for $codec {
+                fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+                    <$inner>::encode_u64(n, buf)
+                }
+
+                fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+                    <$inner>::decode_u64(buf)
+                }
+
+                fn encoded_len(n: u64) -> usize {
+                    <$inner>::encoded_len(n)
+                }
+
+                fn max_len() -> usize {
+                    <$inner>::max_len()
+                }
+
+                fn len_from_prefix(prefix: &[u8]) -> usize {
+                    <$inner>::len_from_prefix(prefix)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_codec_as! {
+    FixedBe => FixedBeN<8>;
+    FixedLe => FixedLeN<8>;
+}
+
+impl IntCodec for Leb128Codec {
+    fn encode_u64(n: u64, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        let leb = encode_uleb128(n);
+        buf[..leb.as_slice().len()].copy_from_slice(leb.as_slice());
+        Ok(leb.len() as usize)
+    }
+
+    fn decode_u64(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+        decode_uleb128(buf)
+    }
+
+    fn encoded_len(n: u64) -> usize {
+        (64 - (n | 1).leading_zeros() as usize).div_ceil(7)
+    }
+
+    fn max_len() -> usize {
+        10
+    }
+
+    fn len_from_prefix(prefix: &[u8]) -> usize {
+        // The last byte read says whether another follows
+        match prefix.last() {
+            Some(byte) if byte & 0x80 != 0 && prefix.len() < 10 => prefix.len() + 1,
+            _ => prefix.len(),
+        }
+    }
+}
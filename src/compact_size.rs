@@ -0,0 +1,253 @@
+//! Bitcoin CompactSize interop.
+//!
+//! CompactSize stores small values in a single byte, and larger values as a marker byte
+//! followed by a little-endian `u16`, `u32` or `u64`:
+//!
+//! ```text
+//! 0x00..=0xFC                1 byte
+//! 0xFD + u16                 3 bytes
+//! 0xFE + u32                 5 bytes
+//! 0xFF + u64                 9 bytes
+//! ```
+//!
+//! Nothing in the format stops a small value from using a wider form. The plain decoders
+//! accept such encodings, while the `_strict` variants reject them with
+//! [`DecodeError::NonCanonical`], as Bitcoin consensus code does.
+
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::io::{Read, Result as IoResult, Write};
+
+use crate::error::DecodeError;
+
+pub(crate) const COMPACT_SIZE_BUF_SIZE: usize = 9;
+
+/// Decode length from the first byte.
+#[inline(always)]
+pub(crate) const fn decode_len_compact_size(first: u8) -> u8 {
+    match first {
+        0xFD => 3,
+        0xFE => 5,
+        0xFF => 9,
+        _ => 1,
+    }
+}
+
+#[inline(always)]
+const fn encode_len_compact_size(n: u64) -> u8 {
+    if n < 0xFD {
+        1
+    } else if n <= u16::MAX as u64 {
+        3
+    } else if n <= u32::MAX as u64 {
+        5
+    } else {
+        9
+    }
+}
+
+/// Encode a u64 as a CompactSize.
+#[inline(always)]
+#[must_use]
+pub const fn encode_compact_size(n: u64) -> CompactSize {
+    let mut out_buf = [0u8; COMPACT_SIZE_BUF_SIZE];
+    let len = encode_len_compact_size(n) as usize;
+    if len == 1 {
+        out_buf[0] = n as u8;
+        return CompactSize(out_buf);
+    }
+
+    out_buf[0] = match len {
+        3 => 0xFD,
+        5 => 0xFE,
+        _ => 0xFF,
+    };
+    let buf = n.to_le_bytes();
+    let mut i = 1;
+    while i < len {
+        out_buf[i] = buf[i - 1];
+        i += 1;
+    }
+    CompactSize(out_buf)
+}
+
+#[inline(always)]
+const fn compact_size_value(n: &CompactSize) -> u64 {
+    let len = n.len() as usize;
+    if len == 1 {
+        return n.0[0] as u64;
+    }
+
+    let mut buf = [0u8; 8];
+    let mut i = 1;
+    while i < len {
+        buf[i - 1] = n.0[i];
+        i += 1;
+    }
+    u64::from_le_bytes(buf)
+}
+
+/// Reject values that would have fit in a shorter form.
+#[inline(always)]
+const fn check_canonical(n: &CompactSize) -> Result<u64, DecodeError> {
+    let value = compact_size_value(n);
+    if encode_len_compact_size(value) != n.len() {
+        return Err(DecodeError::NonCanonical);
+    }
+    Ok(value)
+}
+
+/// Copy a complete encoding off the front of `buf`, along with its length in bytes.
+#[inline(always)]
+fn split_compact_size(buf: &[u8]) -> Option<(CompactSize, usize)> {
+    let len = decode_len_compact_size(*buf.first()?) as usize;
+    let mut out_buf = [0u8; COMPACT_SIZE_BUF_SIZE];
+    out_buf[..len].copy_from_slice(buf.get(..len)?);
+    Some((CompactSize(out_buf), len))
+}
+
+/// Decode a CompactSize from the front of `buf`, accepting non-canonical encodings.
+///
+/// Returns the value and the number of bytes consumed.
+pub fn decode_compact_size(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let (vlq, len) = split_compact_size(buf).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((compact_size_value(&vlq), len))
+}
+
+/// Decode a CompactSize from the front of `buf`, failing if it is not in its shortest
+/// form.
+///
+/// Returns the value and the number of bytes consumed.
+pub fn decode_compact_size_strict(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let (vlq, len) = split_compact_size(buf).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((check_canonical(&vlq)?, len))
+}
+
+/// An unsigned 64-bit integer in Bitcoin CompactSize encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct CompactSize([u8; COMPACT_SIZE_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl CompactSize {
+    /// Maximum length of an encoded `CompactSize` in bytes.
+    pub const MAX_LEN: usize = COMPACT_SIZE_BUF_SIZE;
+
+    /// Construct a new CompactSize from the given `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: u64) -> CompactSize {
+        encode_compact_size(value)
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u64) -> u8 {
+        encode_len_compact_size(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        decode_len_compact_size(first)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        decode_len_compact_size(self.0[0])
+    }
+
+    /// Retrieve the stored number as `u64`.
+    #[inline(always)]
+    pub const fn get(&self) -> u64 {
+        compact_size_value(self)
+    }
+
+    /// Get the raw byte representation of the CompactSize.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 9] {
+        self.0
+    }
+
+    /// Get the serialized representation of the CompactSize as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<u64> for CompactSize {
+    fn from(n: u64) -> Self {
+        encode_compact_size(n)
+    }
+}
+
+impl From<CompactSize> for u64 {
+    fn from(n: CompactSize) -> Self {
+        compact_size_value(&n)
+    }
+}
+
+impl Display for CompactSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for CompactSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "CompactSize(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for reading CompactSizes from a reader.
+pub trait ReadCompactSizeExt {
+    /// Read a CompactSize, accepting non-canonical encodings.
+    fn read_compact_size(&mut self) -> IoResult<u64>;
+
+    /// Read a CompactSize, failing if it is not in its shortest form.
+    fn read_compact_size_strict(&mut self) -> IoResult<u64>;
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for writing CompactSizes to a writer.
+pub trait WriteCompactSizeExt {
+    /// Write a CompactSize.
+    fn write_compact_size(&mut self, n: u64) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+fn read_raw<R: Read + ?Sized>(reader: &mut R) -> IoResult<CompactSize> {
+    let mut buf = [0u8; COMPACT_SIZE_BUF_SIZE];
+    reader.read_exact(&mut buf[0..1])?;
+    let len = decode_len_compact_size(buf[0]) as usize;
+    if len > 1 {
+        reader.read_exact(&mut buf[1..len])?;
+    }
+    Ok(CompactSize(buf))
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadCompactSizeExt for R {
+    fn read_compact_size(&mut self) -> IoResult<u64> {
+        Ok(compact_size_value(&read_raw(self)?))
+    }
+
+    fn read_compact_size_strict(&mut self) -> IoResult<u64> {
+        Ok(check_canonical(&read_raw(self)?)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> WriteCompactSizeExt for W {
+    fn write_compact_size(&mut self, n: u64) -> IoResult<()> {
+        self.write_all(encode_compact_size(n).as_slice())
+    }
+}
@@ -0,0 +1,139 @@
+//! Decimal VLQ encoding for `rust_decimal::Decimal`.
+//!
+//! A decimal is encoded as its scale as a `Vu32`, followed by its mantissa as a `Vi128`.
+//! Scales are at most 28, so the prefix always takes a single byte. The sign of a zero
+//! mantissa is not preserved.
+
+use core::fmt::{Debug, Display};
+
+use rust_decimal::Decimal;
+
+use crate::error::DecodeError;
+use crate::vi128::{Vi128, encode_vi128, zigzag_decode_i128};
+use crate::vu32::{decode_vu32, encode_vu32, split_vu32};
+use crate::vu128::{VU128_BUF_SIZE, Vu128, decode_len_vu128, decode_vu128, split_vu128};
+
+pub(crate) const VDECIMAL_BUF_SIZE: usize = 1 + VU128_BUF_SIZE;
+
+/// Reject scales that `Decimal` cannot represent.
+#[inline(always)]
+pub(crate) const fn check_scale(scale: u32) -> Result<u32, DecodeError> {
+    if scale > Decimal::MAX_SCALE {
+        return Err(DecodeError::InvalidScale(scale));
+    }
+    Ok(scale)
+}
+
+/// Rebuild a decimal from a checked scale and a mantissa, which must fit in 96 bits.
+#[inline(always)]
+pub(crate) fn from_parts(mantissa: i128, scale: u32) -> Result<Decimal, DecodeError> {
+    Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| DecodeError::Overflow)
+}
+
+/// Encode a `Decimal` to VLQ.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vdecimal(n: Decimal) -> VDecimal {
+    let mut out_buf = [0u8; VDECIMAL_BUF_SIZE];
+    out_buf[0] = encode_vu32(n.scale()).bytes()[0];
+    let mantissa = encode_vi128(n.mantissa()).bytes();
+    let mut i = 0;
+    while i < VU128_BUF_SIZE {
+        out_buf[1 + i] = mantissa[i];
+        i += 1;
+    }
+    VDecimal(out_buf)
+}
+
+/// Decode a `Decimal` from the front of `buf`.
+///
+/// Returns the decimal and the number of bytes consumed. Fails if the scale is above 28
+/// or the mantissa does not fit in 96 bits.
+pub fn decode_vdecimal(buf: &[u8]) -> Result<(Decimal, usize), DecodeError> {
+    let (scale, offset) = split_vu32(buf)?;
+    let scale = check_scale(decode_vu32(scale))?;
+    let (mantissa, len) = split_vu128(&buf[offset..]).ok_or(DecodeError::UnexpectedEof)?;
+    let n = from_parts(zigzag_decode_i128(decode_vu128(mantissa)), scale)?;
+    Ok((n, offset + len))
+}
+
+/// A decimal number in value-length quantity encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct VDecimal([u8; VDECIMAL_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl VDecimal {
+    /// Maximum length of an encoded `VDecimal` in bytes.
+    pub const MAX_LEN: usize = VDECIMAL_BUF_SIZE;
+
+    /// Construct a new VLQ instance from the given `Decimal`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: Decimal) -> VDecimal {
+        encode_vdecimal(value)
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: Decimal) -> u8 {
+        1 + Vi128::encoded_len(n.mantissa())
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        1 + decode_len_vu128(self.0[1], self.0[2])
+    }
+
+    /// Retrieve the stored number as `Decimal`.
+    #[inline(always)]
+    pub fn get(&self) -> Decimal {
+        let mut mantissa = [0u8; VU128_BUF_SIZE];
+        mantissa.copy_from_slice(&self.0[1..]);
+        let mantissa = zigzag_decode_i128(decode_vu128(Vu128(mantissa)));
+        // Both parts came from a valid `Decimal`
+        Decimal::from_i128_with_scale(mantissa, unprefix!(1, self.0[0]) as u32)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 19] {
+        self.0
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<Decimal> for VDecimal {
+    fn from(n: Decimal) -> Self {
+        encode_vdecimal(n)
+    }
+}
+
+impl From<VDecimal> for Decimal {
+    fn from(n: VDecimal) -> Self {
+        n.get()
+    }
+}
+
+impl Display for VDecimal {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for VDecimal {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "VDecimal(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
@@ -0,0 +1,120 @@
+//! VLQ extension traits for embedded-io.
+
+use embedded_io::{ErrorType, Read, Write};
+
+use crate::error::ReadError;
+use crate::{decode_vu32, decode_vu64, decode_vu128, encode_vu32, encode_vu64, encode_vu128};
+use crate::{vi32, vi64, vi128, vu32, vu64, vu128};
+
+/// Extension trait for reading VLQ-encoded integers from an `embedded-io` reader.
+pub trait EmbeddedReadVlqExt: ErrorType {
+    /// Read a variable-length `u32`.
+    fn read_vu32(&mut self) -> Result<u32, ReadError<Self::Error>>;
+    /// Read a variable-length `i32`.
+    fn read_vi32(&mut self) -> Result<i32, ReadError<Self::Error>>;
+    /// Read a variable-length `u64`.
+    fn read_vu64(&mut self) -> Result<u64, ReadError<Self::Error>>;
+    /// Read a variable-length `i64`.
+    fn read_vi64(&mut self) -> Result<i64, ReadError<Self::Error>>;
+    /// Read a variable-length `u128`.
+    fn read_vu128(&mut self) -> Result<u128, ReadError<Self::Error>>;
+    /// Read a variable-length `i128`.
+    fn read_vi128(&mut self) -> Result<i128, ReadError<Self::Error>>;
+}
+
+/// Extension trait for writing VLQ-encoded integers to an `embedded-io` writer.
+pub trait EmbeddedWriteVlqExt: ErrorType {
+    /// Write a variable-length `u32`.
+    fn write_vu32(&mut self, n: u32) -> Result<(), Self::Error>;
+    /// Write a variable-length `i32`.
+    fn write_vi32(&mut self, n: i32) -> Result<(), Self::Error>;
+    /// Write a variable-length `u64`.
+    fn write_vu64(&mut self, n: u64) -> Result<(), Self::Error>;
+    /// Write a variable-length `i64`.
+    fn write_vi64(&mut self, n: i64) -> Result<(), Self::Error>;
+    /// Write a variable-length `u128`.
+    fn write_vu128(&mut self, n: u128) -> Result<(), Self::Error>;
+    /// Write a variable-length `i128`.
+    fn write_vi128(&mut self, n: i128) -> Result<(), Self::Error>;
+}
+
+impl<R: Read> EmbeddedReadVlqExt for R {
+    fn read_vu32(&mut self) -> Result<u32, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu32::VU32_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let len = vu32::decode_len_vu32(buf[0]) as usize;
+        if len > 1 {
+            self.read_exact(&mut buf[1..len])?;
+        }
+        let vlq = vu32::Vu32(buf);
+        vlq.validate()?;
+        Ok(decode_vu32(vlq))
+    }
+
+    fn read_vi32(&mut self) -> Result<i32, ReadError<Self::Error>> {
+        self.read_vu32().map(vi32::zigzag_decode_i32)
+    }
+
+    fn read_vu64(&mut self) -> Result<u64, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu64::VU64_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let len = vu64::decode_len_vu64(buf[0]) as usize;
+        if len > 1 {
+            self.read_exact(&mut buf[1..len])?;
+        }
+        let vlq = vu64::Vu64(buf);
+        vlq.validate()?;
+        Ok(decode_vu64(vlq))
+    }
+
+    fn read_vi64(&mut self) -> Result<i64, ReadError<Self::Error>> {
+        self.read_vu64().map(vi64::zigzag_decode_i64)
+    }
+
+    fn read_vu128(&mut self) -> Result<u128, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        // Need second byte to determine extended length
+        let read = if buf[0] == 0 {
+            self.read_exact(&mut buf[1..2])?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
+        if len > read {
+            self.read_exact(&mut buf[read..len])?;
+        }
+        Ok(decode_vu128(vu128::Vu128(buf)))
+    }
+
+    fn read_vi128(&mut self) -> Result<i128, ReadError<Self::Error>> {
+        self.read_vu128().map(vi128::zigzag_decode_i128)
+    }
+}
+
+impl<W: Write> EmbeddedWriteVlqExt for W {
+    fn write_vu32(&mut self, n: u32) -> Result<(), Self::Error> {
+        self.write_all(encode_vu32(n).as_slice())
+    }
+
+    fn write_vi32(&mut self, n: i32) -> Result<(), Self::Error> {
+        self.write_vu32(vi32::zigzag_encode_i32(n))
+    }
+
+    fn write_vu64(&mut self, n: u64) -> Result<(), Self::Error> {
+        self.write_all(encode_vu64(n).as_slice())
+    }
+
+    fn write_vi64(&mut self, n: i64) -> Result<(), Self::Error> {
+        self.write_vu64(vi64::zigzag_encode_i64(n))
+    }
+
+    fn write_vu128(&mut self, n: u128) -> Result<(), Self::Error> {
+        self.write_all(encode_vu128(n).as_slice())
+    }
+
+    fn write_vi128(&mut self, n: i128) -> Result<(), Self::Error> {
+        self.write_vu128(vi128::zigzag_encode_i128(n))
+    }
+}
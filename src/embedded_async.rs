@@ -0,0 +1,141 @@
+//! Async VLQ extension traits for embedded-io-async.
+
+use embedded_io_async::{ErrorType, Read, Write};
+
+use crate::error::ReadError;
+use crate::{decode_vu32, decode_vu64, decode_vu128, encode_vu32, encode_vu64, encode_vu128};
+use crate::{vi32, vi64, vi128, vu32, vu64, vu128};
+
+/// Extension trait for reading VLQ-encoded integers from an `embedded-io-async` reader.
+pub trait EmbeddedAsyncReadVlqExt: ErrorType {
+    /// Read a variable-length `u32` asynchronously.
+    fn read_vu32(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<u32, ReadError<Self::Error>>>;
+    /// Read a variable-length `i32` asynchronously.
+    fn read_vi32(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<i32, ReadError<Self::Error>>>;
+    /// Read a variable-length `u64` asynchronously.
+    fn read_vu64(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<u64, ReadError<Self::Error>>>;
+    /// Read a variable-length `i64` asynchronously.
+    fn read_vi64(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<i64, ReadError<Self::Error>>>;
+    /// Read a variable-length `u128` asynchronously.
+    fn read_vu128(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<u128, ReadError<Self::Error>>>;
+    /// Read a variable-length `i128` asynchronously.
+    fn read_vi128(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<i128, ReadError<Self::Error>>>;
+}
+
+/// Extension trait for writing VLQ-encoded integers to an `embedded-io-async` writer.
+pub trait EmbeddedAsyncWriteVlqExt: ErrorType {
+    /// Write a variable-length `u32` asynchronously.
+    fn write_vu32(&mut self, n: u32)
+    -> impl core::future::Future<Output = Result<(), Self::Error>>;
+    /// Write a variable-length `i32` asynchronously.
+    fn write_vi32(&mut self, n: i32)
+    -> impl core::future::Future<Output = Result<(), Self::Error>>;
+    /// Write a variable-length `u64` asynchronously.
+    fn write_vu64(&mut self, n: u64)
+    -> impl core::future::Future<Output = Result<(), Self::Error>>;
+    /// Write a variable-length `i64` asynchronously.
+    fn write_vi64(&mut self, n: i64)
+    -> impl core::future::Future<Output = Result<(), Self::Error>>;
+    /// Write a variable-length `u128` asynchronously.
+    fn write_vu128(
+        &mut self,
+        n: u128,
+    ) -> impl core::future::Future<Output = Result<(), Self::Error>>;
+    /// Write a variable-length `i128` asynchronously.
+    fn write_vi128(
+        &mut self,
+        n: i128,
+    ) -> impl core::future::Future<Output = Result<(), Self::Error>>;
+}
+
+impl<R: Read> EmbeddedAsyncReadVlqExt for R {
+    async fn read_vu32(&mut self) -> Result<u32, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu32::VU32_BUF_SIZE];
+        Read::read_exact(self, &mut buf[0..1]).await?;
+        let len = vu32::decode_len_vu32(buf[0]) as usize;
+        if len > 1 {
+            Read::read_exact(self, &mut buf[1..len]).await?;
+        }
+        let vlq = vu32::Vu32(buf);
+        vlq.validate()?;
+        Ok(decode_vu32(vlq))
+    }
+
+    async fn read_vi32(&mut self) -> Result<i32, ReadError<Self::Error>> {
+        self.read_vu32().await.map(vi32::zigzag_decode_i32)
+    }
+
+    async fn read_vu64(&mut self) -> Result<u64, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu64::VU64_BUF_SIZE];
+        Read::read_exact(self, &mut buf[0..1]).await?;
+        let len = vu64::decode_len_vu64(buf[0]) as usize;
+        if len > 1 {
+            Read::read_exact(self, &mut buf[1..len]).await?;
+        }
+        let vlq = vu64::Vu64(buf);
+        vlq.validate()?;
+        Ok(decode_vu64(vlq))
+    }
+
+    async fn read_vi64(&mut self) -> Result<i64, ReadError<Self::Error>> {
+        self.read_vu64().await.map(vi64::zigzag_decode_i64)
+    }
+
+    async fn read_vu128(&mut self) -> Result<u128, ReadError<Self::Error>> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        Read::read_exact(self, &mut buf[0..1]).await?;
+        let read = if buf[0] == 0 {
+            Read::read_exact(self, &mut buf[1..2]).await?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
+        if len > read {
+            Read::read_exact(self, &mut buf[read..len]).await?;
+        }
+        Ok(decode_vu128(vu128::Vu128(buf)))
+    }
+
+    async fn read_vi128(&mut self) -> Result<i128, ReadError<Self::Error>> {
+        self.read_vu128().await.map(vi128::zigzag_decode_i128)
+    }
+}
+
+impl<W: Write> EmbeddedAsyncWriteVlqExt for W {
+    async fn write_vu32(&mut self, n: u32) -> Result<(), Self::Error> {
+        Write::write_all(self, encode_vu32(n).as_slice()).await
+    }
+
+    async fn write_vi32(&mut self, n: i32) -> Result<(), Self::Error> {
+        self.write_vu32(vi32::zigzag_encode_i32(n)).await
+    }
+
+    async fn write_vu64(&mut self, n: u64) -> Result<(), Self::Error> {
+        Write::write_all(self, encode_vu64(n).as_slice()).await
+    }
+
+    async fn write_vi64(&mut self, n: i64) -> Result<(), Self::Error> {
+        self.write_vu64(vi64::zigzag_encode_i64(n)).await
+    }
+
+    async fn write_vu128(&mut self, n: u128) -> Result<(), Self::Error> {
+        Write::write_all(self, encode_vu128(n).as_slice()).await
+    }
+
+    async fn write_vi128(&mut self, n: i128) -> Result<(), Self::Error> {
+        self.write_vu128(vi128::zigzag_encode_i128(n)).await
+    }
+}
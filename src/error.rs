@@ -0,0 +1,147 @@
+//! Error types.
+
+use core::fmt::Display;
+
+/// An error encountered while decoding a VLQ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input ended before the complete encoding could be read.
+    UnexpectedEof,
+    /// A length prefix exceeded the caller-supplied maximum.
+    LengthLimitExceeded {
+        /// The decoded length.
+        len: u64,
+        /// The maximum length allowed.
+        max: usize,
+    },
+    /// A length-prefixed string was not valid UTF-8.
+    InvalidUtf8(core::str::Utf8Error),
+    /// The encoded value does not fit in the target type.
+    Overflow,
+    /// A decimal scale was larger than the target type supports.
+    InvalidScale(u32),
+    /// A subsecond nanosecond count was not below one billion.
+    InvalidNanos(u32),
+    /// A value was encoded in a longer form than necessary.
+    NonCanonical,
+    /// An enum discriminant did not match any variant.
+    InvalidDiscriminant(u32),
+    /// A boolean was neither 0 nor 1.
+    InvalidBool(u32),
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof => f.write_str("unexpected end of input"),
+            DecodeError::LengthLimitExceeded { len, max } => {
+                write!(f, "length {len} exceeds maximum of {max}")
+            }
+            DecodeError::InvalidUtf8(e) => write!(f, "invalid UTF-8: {e}"),
+            DecodeError::Overflow => f.write_str("value overflows the target type"),
+            DecodeError::InvalidScale(scale) => write!(f, "invalid decimal scale {scale}"),
+            DecodeError::InvalidNanos(nanos) => write!(f, "invalid subsecond nanos {nanos}"),
+            DecodeError::NonCanonical => f.write_str("non-canonical encoding"),
+            DecodeError::InvalidDiscriminant(d) => write!(f, "invalid enum discriminant {d}"),
+            DecodeError::InvalidBool(n) => write!(f, "invalid boolean {n}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DecodeError::InvalidUtf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DecodeError> for std::io::Error {
+    fn from(e: DecodeError) -> Self {
+        let kind = match e {
+            DecodeError::UnexpectedEof => std::io::ErrorKind::UnexpectedEof,
+            _ => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, e)
+    }
+}
+
+/// An error encountered while encoding a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The value is outside the range the encoding can represent.
+    Overflow,
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            EncodeError::Overflow => f.write_str("value does not fit the encoding"),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+#[cfg(feature = "std")]
+impl From<EncodeError> for std::io::Error {
+    fn from(e: EncodeError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+/// An error encountered while reading a VLQ from an `embedded-io` reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError<E> {
+    /// The underlying reader failed.
+    Io(E),
+    /// The bytes read were not a valid encoding.
+    Decode(DecodeError),
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E> From<DecodeError> for ReadError<E> {
+    fn from(e: DecodeError) -> Self {
+        ReadError::Decode(e)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E> From<embedded_io::ReadExactError<E>> for ReadError<E> {
+    fn from(e: embedded_io::ReadExactError<E>) -> Self {
+        match e {
+            embedded_io::ReadExactError::UnexpectedEof => {
+                ReadError::Decode(DecodeError::UnexpectedEof)
+            }
+            embedded_io::ReadExactError::Other(e) => ReadError::Io(e),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E: Display> Display for ReadError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            ReadError::Io(e) => Display::fmt(e, f),
+            ReadError::Decode(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E: core::error::Error> core::error::Error for ReadError<E> {}
+
+#[cfg(feature = "embedded-io")]
+impl<E: embedded_io::Error> embedded_io::Error for ReadError<E> {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            ReadError::Io(e) => e.kind(),
+            ReadError::Decode(_) => embedded_io::ErrorKind::InvalidData,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 //! Async VLQ extension traits.
 
+use crate::IntCodec;
+
 /// Extension trait for reading VLQ-encoded integers from an async reader.
 pub trait AsyncReadVlqExt {
     /// Read a variable-length `u32` asynchronously.
@@ -14,6 +16,66 @@ pub trait AsyncReadVlqExt {
     fn read_vu128(&mut self) -> impl core::future::Future<Output = std::io::Result<u128>>;
     /// Read a variable-length `i128` asynchronously.
     fn read_vi128(&mut self) -> impl core::future::Future<Output = std::io::Result<i128>>;
+    /// Read a variable-length `u128` in the v2 layout asynchronously.
+    fn read_vu128_v2(&mut self) -> impl core::future::Future<Output = std::io::Result<u128>>;
+    /// Read a variable-length big-endian `u256` asynchronously.
+    fn read_vu256(&mut self) -> impl core::future::Future<Output = std::io::Result<[u8; 32]>>;
+    /// Read a variable-length big-endian two's complement `i256` asynchronously.
+    fn read_vi256(&mut self) -> impl core::future::Future<Output = std::io::Result<[u8; 32]>>;
+    /// Read a variable-length `f32` asynchronously.
+    fn read_vf32(&mut self) -> impl core::future::Future<Output = std::io::Result<f32>>;
+    /// Read a variable-length `f64` asynchronously.
+    fn read_vf64(&mut self) -> impl core::future::Future<Output = std::io::Result<f64>>;
+    /// Read a variable-length `Decimal` asynchronously, rejecting scales above 28.
+    #[cfg(feature = "rust_decimal")]
+    fn read_vdecimal(
+        &mut self,
+    ) -> impl core::future::Future<Output = std::io::Result<rust_decimal::Decimal>>;
+    /// Read a `Duration` as whole seconds and subsecond nanoseconds asynchronously.
+    fn read_duration(
+        &mut self,
+    ) -> impl core::future::Future<Output = std::io::Result<std::time::Duration>>;
+    /// Read a `SystemTime` as signed seconds since the Unix epoch and subsecond
+    /// nanoseconds asynchronously.
+    fn read_system_time(
+        &mut self,
+    ) -> impl core::future::Future<Output = std::io::Result<std::time::SystemTime>>;
+    /// Read an arbitrary-precision integer as a big-endian magnitude asynchronously.
+    /// Long forms of more than `max_len` bytes are rejected.
+    fn read_vubig(
+        &mut self,
+        max_len: usize,
+    ) -> impl core::future::Future<Output = std::io::Result<Vec<u8>>>;
+    /// Read a length-prefixed byte string of at most `max_len` bytes asynchronously.
+    fn read_vbytes(
+        &mut self,
+        max_len: usize,
+    ) -> impl core::future::Future<Output = std::io::Result<Vec<u8>>>;
+    /// Read a length-prefixed UTF-8 string of at most `max_len` bytes asynchronously.
+    fn read_vstring(
+        &mut self,
+        max_len: usize,
+    ) -> impl core::future::Future<Output = std::io::Result<String>>;
+    /// Read a `u64` in the encoding given by the codec `C` asynchronously.
+    fn read_u64_with<C: IntCodec>(
+        &mut self,
+    ) -> impl core::future::Future<Output = std::io::Result<u64>>;
+    /// Skip a variable-length `u64` or `i64` asynchronously, returning its length.
+    fn skip_vu64(&mut self) -> impl core::future::Future<Output = std::io::Result<usize>>;
+    /// Skip a variable-length `u128` or `i128` asynchronously, returning its length.
+    fn skip_vu128(&mut self) -> impl core::future::Future<Output = std::io::Result<usize>>;
+    /// Skip `count` variable-length `u64`s or `i64`s asynchronously, returning the total
+    /// bytes skipped.
+    fn skip_n_vu64(
+        &mut self,
+        count: usize,
+    ) -> impl core::future::Future<Output = std::io::Result<usize>>;
+    /// Skip `count` variable-length `u128`s or `i128`s asynchronously, returning the
+    /// total bytes skipped.
+    fn skip_n_vu128(
+        &mut self,
+        count: usize,
+    ) -> impl core::future::Future<Output = std::io::Result<usize>>;
 }
 
 /// Extension trait for writing VLQ-encoded integers to an async writer.
@@ -30,4 +92,57 @@ pub trait AsyncWriteVlqExt {
     fn write_vu128(&mut self, n: u128) -> impl core::future::Future<Output = std::io::Result<()>>;
     /// Write a variable-length `i128` asynchronously.
     fn write_vi128(&mut self, n: i128) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length `u128` in the v2 layout asynchronously.
+    fn write_vu128_v2(
+        &mut self,
+        n: u128,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length big-endian `u256` asynchronously.
+    fn write_vu256(
+        &mut self,
+        n: [u8; 32],
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length big-endian two's complement `i256` asynchronously.
+    fn write_vi256(
+        &mut self,
+        n: [u8; 32],
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length `f32` asynchronously.
+    fn write_vf32(&mut self, n: f32) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length `f64` asynchronously.
+    fn write_vf64(&mut self, n: f64) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a variable-length `Decimal` asynchronously.
+    #[cfg(feature = "rust_decimal")]
+    fn write_vdecimal(
+        &mut self,
+        n: rust_decimal::Decimal,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a `Duration` as whole seconds and subsecond nanoseconds asynchronously.
+    fn write_duration(
+        &mut self,
+        d: std::time::Duration,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a `SystemTime` as signed seconds since the Unix epoch and subsecond
+    /// nanoseconds asynchronously.
+    fn write_system_time(
+        &mut self,
+        t: std::time::SystemTime,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write an arbitrary-precision integer from its big-endian magnitude asynchronously.
+    fn write_vubig(
+        &mut self,
+        magnitude: &[u8],
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a length-prefixed byte string asynchronously.
+    fn write_vbytes(
+        &mut self,
+        bytes: &[u8],
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a length-prefixed UTF-8 string asynchronously.
+    fn write_vstr(&mut self, s: &str) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a `u64` in the encoding given by the codec `C` asynchronously.
+    fn write_u64_with<C: IntCodec>(
+        &mut self,
+        n: u64,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
 }
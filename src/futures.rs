@@ -1,11 +1,20 @@
 //! Async VLQ trait implementations for futures-io.
 
+use std::time::{Duration, SystemTime};
+
 use futures_io::{AsyncRead, AsyncWrite};
 use futures_util::{AsyncReadExt, AsyncWriteExt};
 
 use crate::ext::{AsyncReadVlqExt, AsyncWriteVlqExt};
-use crate::{decode_vu32, decode_vu64, decode_vu128, encode_vu32, encode_vu64, encode_vu128};
-use crate::{vi32, vi64, vi128, vu32, vu64, vu128};
+use crate::{DecodeError, EncodeError, IntCodec, MAX_CODEC_LEN};
+use crate::{
+    bytes, time, vf32, vf64, vi32, vi64, vi128, vi256, vu32, vu64, vu128, vu128_v2, vu256, vubig,
+};
+use crate::{decode_vu32, decode_vu64, decode_vu128, decode_vu128_v2, decode_vu256};
+use crate::{
+    encode_big, encode_duration, encode_vu32, encode_vu64, encode_vu128, encode_vu128_v2,
+    encode_vu256,
+};
 
 impl<R: AsyncRead + Unpin> AsyncReadVlqExt for R {
     async fn read_vu32(&mut self) -> std::io::Result<u32> {
@@ -15,7 +24,9 @@ impl<R: AsyncRead + Unpin> AsyncReadVlqExt for R {
         if len > 1 {
             AsyncReadExt::read_exact(self, &mut buf[1..len]).await?;
         }
-        Ok(decode_vu32(vu32::Vu32(buf)))
+        let vlq = vu32::Vu32(buf);
+        vlq.validate()?;
+        Ok(decode_vu32(vlq))
     }
 
     async fn read_vi32(&mut self) -> std::io::Result<i32> {
@@ -29,7 +40,9 @@ impl<R: AsyncRead + Unpin> AsyncReadVlqExt for R {
         if len > 1 {
             AsyncReadExt::read_exact(self, &mut buf[1..len]).await?;
         }
-        Ok(decode_vu64(vu64::Vu64(buf)))
+        let vlq = vu64::Vu64(buf);
+        vlq.validate()?;
+        Ok(decode_vu64(vlq))
     }
 
     async fn read_vi64(&mut self) -> std::io::Result<i64> {
@@ -39,14 +52,15 @@ impl<R: AsyncRead + Unpin> AsyncReadVlqExt for R {
     async fn read_vu128(&mut self) -> std::io::Result<u128> {
         let mut buf = [0u8; vu128::VU128_BUF_SIZE];
         AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
-        if buf[0] == 0 {
+        let read = if buf[0] == 0 {
             AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
-        }
+            2
+        } else {
+            1
+        };
         let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
-        if len > 2 {
-            AsyncReadExt::read_exact(self, &mut buf[2..len]).await?;
-        } else if len == 2 && buf[0] != 0 {
-            AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
+        if len > read {
+            AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
         }
         Ok(decode_vu128(vu128::Vu128(buf)))
     }
@@ -54,6 +68,177 @@ impl<R: AsyncRead + Unpin> AsyncReadVlqExt for R {
     async fn read_vi128(&mut self) -> std::io::Result<i128> {
         self.read_vu128().await.map(vi128::zigzag_decode_i128)
     }
+
+    async fn read_vu128_v2(&mut self) -> std::io::Result<u128> {
+        let mut buf = [0u8; vu128_v2::VU128_V2_BUF_SIZE];
+        AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+        let read = if buf[0] == 1 {
+            AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
+            2
+        } else {
+            1
+        };
+        let len = vu128_v2::decode_len_vu128_v2(buf[0], buf[1]) as usize;
+        if len > read {
+            AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
+        }
+        let vlq = vu128_v2::Vu128V2(buf);
+        vlq.validate()?;
+        Ok(decode_vu128_v2(vlq))
+    }
+
+    async fn read_vu256(&mut self) -> std::io::Result<[u8; 32]> {
+        let mut buf = [0u8; vu256::VU256_BUF_SIZE];
+        AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+        let mut read = 1;
+        if buf[0] == 0 {
+            AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
+            read = 2;
+        }
+        let len = if buf[0] == 0 && buf[1] == 0 {
+            AsyncReadExt::read_exact(self, &mut buf[2..3]).await?;
+            read = 3;
+            vu256::decode_long_len_vu256(buf[2])?
+        } else {
+            vu256::decode_len_vu256(buf[0], buf[1], buf[2])
+        } as usize;
+        if len > read {
+            AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
+        }
+        Ok(decode_vu256(vu256::Vu256(buf)))
+    }
+
+    async fn read_vi256(&mut self) -> std::io::Result<[u8; 32]> {
+        self.read_vu256().await.map(vi256::zigzag_decode_i256)
+    }
+
+    async fn read_vf32(&mut self) -> std::io::Result<f32> {
+        self.read_vu32().await.map(vf32::float_decode_f32)
+    }
+
+    async fn read_vf64(&mut self) -> std::io::Result<f64> {
+        self.read_vu64().await.map(vf64::float_decode_f64)
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    async fn read_vdecimal(&mut self) -> std::io::Result<rust_decimal::Decimal> {
+        let scale = crate::decimal::check_scale(self.read_vu32().await?)?;
+        let mantissa = self.read_vi128().await?;
+        Ok(crate::decimal::from_parts(mantissa, scale)?)
+    }
+
+    async fn read_duration(&mut self) -> std::io::Result<Duration> {
+        let secs = self.read_vu64().await?;
+        let nanos = time::check_nanos(self.read_vu32().await?)?;
+        Ok(Duration::new(secs, nanos))
+    }
+
+    async fn read_system_time(&mut self) -> std::io::Result<SystemTime> {
+        let secs = self.read_vi64().await?;
+        let nanos = time::check_nanos(self.read_vu32().await?)?;
+        Ok(time::join_system_time(secs, nanos)?)
+    }
+
+    async fn read_vubig(&mut self, max_len: usize) -> std::io::Result<Vec<u8>> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+        let read = if buf[0] == 0 {
+            AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]);
+        if len <= vubig::SHORT_MAX_LEN {
+            let len = len as usize;
+            if len > read {
+                AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
+            }
+            return Ok(vubig::short_magnitude(vu128::Vu128(buf)));
+        }
+
+        let magnitude = self.read_vbytes(max_len).await?;
+        Ok(vubig::trim_magnitude(&magnitude).to_vec())
+    }
+
+    async fn read_vbytes(&mut self, max_len: usize) -> std::io::Result<Vec<u8>> {
+        let len = bytes::check_len(self.read_vu64().await?, max_len)?;
+        let mut buf = Vec::new();
+        AsyncReadExt::take(&mut *self, len as u64)
+            .read_to_end(&mut buf)
+            .await?;
+        if buf.len() != len {
+            return Err(DecodeError::UnexpectedEof.into());
+        }
+        Ok(buf)
+    }
+
+    async fn read_vstring(&mut self, max_len: usize) -> std::io::Result<String> {
+        let buf = self.read_vbytes(max_len).await?;
+        String::from_utf8(buf).map_err(|e| DecodeError::InvalidUtf8(e.utf8_error()).into())
+    }
+
+    async fn read_u64_with<C: IntCodec>(&mut self) -> std::io::Result<u64> {
+        let mut buf = [0u8; MAX_CODEC_LEN];
+        let mut read = 0;
+        let mut len = 1;
+        // Grow the prefix until the codec knows the full length
+        while len > read {
+            if len > MAX_CODEC_LEN {
+                return Err(DecodeError::LengthLimitExceeded {
+                    len: len as u64,
+                    max: MAX_CODEC_LEN,
+                }
+                .into());
+            }
+            AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
+            read = len;
+            len = C::len_from_prefix(&buf[..read]);
+        }
+        Ok(C::decode_u64(&buf[..read])?.0)
+    }
+
+    async fn skip_vu64(&mut self) -> std::io::Result<usize> {
+        let mut buf = [0u8; vu64::VU64_BUF_SIZE];
+        AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+        let len = vu64::decode_len_vu64(buf[0]) as usize;
+        if len > 1 {
+            AsyncReadExt::read_exact(self, &mut buf[1..len]).await?;
+        }
+        Ok(len)
+    }
+
+    async fn skip_vu128(&mut self) -> std::io::Result<usize> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+        let read = if buf[0] == 0 {
+            AsyncReadExt::read_exact(self, &mut buf[1..2]).await?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
+        if len > read {
+            AsyncReadExt::read_exact(self, &mut buf[read..len]).await?;
+        }
+        Ok(len)
+    }
+
+    async fn skip_n_vu64(&mut self, count: usize) -> std::io::Result<usize> {
+        let mut total = 0;
+        for _ in 0..count {
+            total += self.skip_vu64().await?;
+        }
+        Ok(total)
+    }
+
+    async fn skip_n_vu128(&mut self, count: usize) -> std::io::Result<usize> {
+        let mut total = 0;
+        for _ in 0..count {
+            total += self.skip_vu128().await?;
+        }
+        Ok(total)
+    }
 }
 
 impl<W: AsyncWrite + Unpin> AsyncWriteVlqExt for W {
@@ -80,4 +265,58 @@ impl<W: AsyncWrite + Unpin> AsyncWriteVlqExt for W {
     async fn write_vi128(&mut self, n: i128) -> std::io::Result<()> {
         self.write_vu128(vi128::zigzag_encode_i128(n)).await
     }
+
+    async fn write_vu128_v2(&mut self, n: u128) -> std::io::Result<()> {
+        AsyncWriteExt::write_all(self, encode_vu128_v2(n).as_slice()).await
+    }
+
+    async fn write_vu256(&mut self, n: [u8; 32]) -> std::io::Result<()> {
+        AsyncWriteExt::write_all(self, encode_vu256(n).as_slice()).await
+    }
+
+    async fn write_vi256(&mut self, n: [u8; 32]) -> std::io::Result<()> {
+        self.write_vu256(vi256::zigzag_encode_i256(n)).await
+    }
+
+    async fn write_vf32(&mut self, n: f32) -> std::io::Result<()> {
+        self.write_vu32(vf32::float_encode_f32(n)).await
+    }
+
+    async fn write_vf64(&mut self, n: f64) -> std::io::Result<()> {
+        self.write_vu64(vf64::float_encode_f64(n)).await
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    async fn write_vdecimal(&mut self, n: rust_decimal::Decimal) -> std::io::Result<()> {
+        AsyncWriteExt::write_all(self, crate::encode_vdecimal(n).as_slice()).await
+    }
+
+    async fn write_duration(&mut self, d: Duration) -> std::io::Result<()> {
+        AsyncWriteExt::write_all(self, encode_duration(d).as_slice()).await
+    }
+
+    async fn write_system_time(&mut self, t: SystemTime) -> std::io::Result<()> {
+        let (secs, nanos) = time::split_system_time(t).ok_or(EncodeError::Overflow)?;
+        self.write_vi64(secs).await?;
+        self.write_vu32(nanos).await
+    }
+
+    async fn write_vubig(&mut self, magnitude: &[u8]) -> std::io::Result<()> {
+        AsyncWriteExt::write_all(self, encode_big(magnitude).as_slice()).await
+    }
+
+    async fn write_vbytes(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.write_vu64(bytes.len() as u64).await?;
+        AsyncWriteExt::write_all(self, bytes).await
+    }
+
+    async fn write_vstr(&mut self, s: &str) -> std::io::Result<()> {
+        self.write_vbytes(s.as_bytes()).await
+    }
+
+    async fn write_u64_with<C: IntCodec>(&mut self, n: u64) -> std::io::Result<()> {
+        let mut buf = [0u8; MAX_CODEC_LEN];
+        let len = C::encode_u64(n, &mut buf)?;
+        AsyncWriteExt::write_all(self, &buf[..len]).await
+    }
 }
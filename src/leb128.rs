@@ -0,0 +1,464 @@
+//! LEB128 interop.
+//!
+//! Unsigned and signed LEB128 as used by protobuf, DWARF and WebAssembly, with the same
+//! slice, reader and writer shapes as the native types. Decoding accepts padded
+//! encodings but rejects anything that overflows 64 bits.
+//!
+//! [`leb128_to_vu64`] and [`vu64_to_leb128`] transcode whole streams of unsigned values
+//! between the two formats.
+
+use core::fmt::{Debug, Display};
+
+use crate::error::DecodeError;
+#[cfg(feature = "std")]
+use crate::{ReadVlqExt, WriteVlqExt};
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read, Result as IoResult, Write};
+
+pub(crate) const LEB128_BUF_SIZE: usize = 10;
+
+/// Length of the encoding at the front of `buf`, found from its continuation bits.
+#[inline(always)]
+const fn encoded_len(buf: &[u8; LEB128_BUF_SIZE]) -> u8 {
+    let mut i = 0;
+    while i < LEB128_BUF_SIZE - 1 && buf[i] & 0x80 != 0 {
+        i += 1;
+    }
+    i as u8 + 1
+}
+
+/// Encode a u64 in unsigned LEB128.
+#[inline(always)]
+#[must_use]
+pub const fn encode_uleb128(mut n: u64) -> Uleb128 {
+    let mut out_buf = [0u8; LEB128_BUF_SIZE];
+    let mut i = 0;
+    loop {
+        let byte = (n & 0x7F) as u8;
+        n >>= 7;
+        if n == 0 {
+            out_buf[i] = byte;
+            return Uleb128(out_buf);
+        }
+        out_buf[i] = byte | 0x80;
+        i += 1;
+    }
+}
+
+/// Decode an unsigned LEB128 from the front of `buf`.
+///
+/// Returns the value and the number of bytes consumed.
+pub const fn decode_uleb128(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let mut n = 0u64;
+    let mut i = 0;
+    while i < buf.len() {
+        let byte = buf[i];
+        // The tenth byte carries only the top bit and must end the encoding
+        if i == LEB128_BUF_SIZE - 1 && byte > 0x01 {
+            return Err(DecodeError::Overflow);
+        }
+        n |= ((byte & 0x7F) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((n, i + 1));
+        }
+        i += 1;
+    }
+    Err(DecodeError::UnexpectedEof)
+}
+
+/// Encode an i64 in signed LEB128.
+#[inline(always)]
+#[must_use]
+pub const fn encode_sleb128(mut n: i64) -> Sleb128 {
+    let mut out_buf = [0u8; LEB128_BUF_SIZE];
+    let mut i = 0;
+    loop {
+        let byte = (n & 0x7F) as u8;
+        n >>= 7;
+        if (n == 0 && byte & 0x40 == 0) || (n == -1 && byte & 0x40 != 0) {
+            out_buf[i] = byte;
+            return Sleb128(out_buf);
+        }
+        out_buf[i] = byte | 0x80;
+        i += 1;
+    }
+}
+
+/// Decode a signed LEB128 from the front of `buf`.
+///
+/// Returns the value and the number of bytes consumed.
+pub const fn decode_sleb128(buf: &[u8]) -> Result<(i64, usize), DecodeError> {
+    let mut n = 0i64;
+    let mut i = 0;
+    while i < buf.len() {
+        let byte = buf[i];
+        // The tenth byte carries only the top bit, sign-extended, and must end the encoding
+        if i == LEB128_BUF_SIZE - 1 && byte != 0x00 && byte != 0x7F {
+            return Err(DecodeError::Overflow);
+        }
+        n |= ((byte & 0x7F) as i64) << (7 * i);
+        if byte & 0x80 == 0 {
+            if i < LEB128_BUF_SIZE - 1 && byte & 0x40 != 0 {
+                n |= -1 << (7 * (i + 1));
+            }
+            return Ok((n, i + 1));
+        }
+        i += 1;
+    }
+    Err(DecodeError::UnexpectedEof)
+}
+
+/// An unsigned 64-bit integer in LEB128 encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Uleb128([u8; LEB128_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl Uleb128 {
+    /// Maximum length of an encoded `Uleb128` in bytes.
+    pub const MAX_LEN: usize = LEB128_BUF_SIZE;
+
+    /// Construct a new LEB128 instance from the given `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: u64) -> Uleb128 {
+        encode_uleb128(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        encoded_len(&self.0)
+    }
+
+    /// Retrieve the stored number as `u64`.
+    #[inline(always)]
+    pub const fn get(&self) -> u64 {
+        match decode_uleb128(&self.0) {
+            Ok((n, _)) => n,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Get the raw byte representation of the LEB128 instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 10] {
+        self.0
+    }
+
+    /// Get the serialized representation of the LEB128 as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<u64> for Uleb128 {
+    fn from(n: u64) -> Self {
+        encode_uleb128(n)
+    }
+}
+
+impl From<Uleb128> for u64 {
+    fn from(n: Uleb128) -> Self {
+        n.get()
+    }
+}
+
+impl Display for Uleb128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Uleb128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Uleb128(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+/// A signed 64-bit integer in LEB128 encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Sleb128([u8; LEB128_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl Sleb128 {
+    /// Maximum length of an encoded `Sleb128` in bytes.
+    pub const MAX_LEN: usize = LEB128_BUF_SIZE;
+
+    /// Construct a new LEB128 instance from the given `i64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: i64) -> Sleb128 {
+        encode_sleb128(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        encoded_len(&self.0)
+    }
+
+    /// Retrieve the stored number as `i64`.
+    #[inline(always)]
+    pub const fn get(&self) -> i64 {
+        match decode_sleb128(&self.0) {
+            Ok((n, _)) => n,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Get the raw byte representation of the LEB128 instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 10] {
+        self.0
+    }
+
+    /// Get the serialized representation of the LEB128 as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<i64> for Sleb128 {
+    fn from(n: i64) -> Self {
+        encode_sleb128(n)
+    }
+}
+
+impl From<Sleb128> for i64 {
+    fn from(n: Sleb128) -> Self {
+        n.get()
+    }
+}
+
+impl Display for Sleb128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Sleb128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Sleb128(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for reading LEB128-encoded integers from a reader.
+pub trait ReadLeb128Ext {
+    /// Read an unsigned LEB128 `u64`.
+    fn read_uleb128(&mut self) -> IoResult<u64>;
+    /// Read a signed LEB128 `i64`.
+    fn read_sleb128(&mut self) -> IoResult<i64>;
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for writing LEB128-encoded integers to a writer.
+pub trait WriteLeb128Ext {
+    /// Write an unsigned LEB128 `u64`.
+    fn write_uleb128(&mut self, n: u64) -> IoResult<()>;
+    /// Write a signed LEB128 `i64`.
+    fn write_sleb128(&mut self, n: i64) -> IoResult<()>;
+}
+
+/// Read the bytes of one encoding after its first byte, stopping at the last byte or
+/// after the maximum length.
+#[cfg(feature = "std")]
+fn read_rest<R: Read + ?Sized>(reader: &mut R, first: u8) -> IoResult<[u8; LEB128_BUF_SIZE]> {
+    let mut buf = [0u8; LEB128_BUF_SIZE];
+    buf[0] = first;
+    let mut i = 0;
+    while buf[i] & 0x80 != 0 && i < LEB128_BUF_SIZE - 1 {
+        i += 1;
+        reader.read_exact(&mut buf[i..i + 1])?;
+    }
+    Ok(buf)
+}
+
+/// Read the first byte of the next value, or `None` at the end of the stream.
+#[cfg(feature = "std")]
+fn read_first<R: Read + ?Sized>(reader: &mut R) -> IoResult<Option<u8>> {
+    let mut buf = [0u8; 1];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(buf[0])),
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadLeb128Ext for R {
+    fn read_uleb128(&mut self) -> IoResult<u64> {
+        let mut first = [0u8; 1];
+        self.read_exact(&mut first)?;
+        let buf = read_rest(self, first[0])?;
+        Ok(decode_uleb128(&buf)?.0)
+    }
+
+    fn read_sleb128(&mut self) -> IoResult<i64> {
+        let mut first = [0u8; 1];
+        self.read_exact(&mut first)?;
+        let buf = read_rest(self, first[0])?;
+        Ok(decode_sleb128(&buf)?.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> WriteLeb128Ext for W {
+    fn write_uleb128(&mut self, n: u64) -> IoResult<()> {
+        self.write_all(encode_uleb128(n).as_slice())
+    }
+
+    fn write_sleb128(&mut self, n: i64) -> IoResult<()> {
+        self.write_all(encode_sleb128(n).as_slice())
+    }
+}
+
+/// Transcode a stream of unsigned LEB128 values to `Vu64`s until `reader` ends.
+///
+/// Returns the number of values transcoded. Fails if the stream ends partway through a
+/// value. Neither side is buffered, so wrap them in `BufReader` and `BufWriter`.
+#[cfg(feature = "std")]
+pub fn leb128_to_vu64<R: Read, W: Write>(mut reader: R, mut writer: W) -> IoResult<u64> {
+    let mut count = 0;
+    while let Some(first) = read_first(&mut reader)? {
+        let buf = read_rest(&mut reader, first)?;
+        writer.write_vu64(decode_uleb128(&buf)?.0)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Transcode a stream of `Vu64`s to unsigned LEB128 values until `reader` ends.
+///
+/// Returns the number of values transcoded. Fails if the stream ends partway through a
+/// value. Neither side is buffered, so wrap them in `BufReader` and `BufWriter`.
+#[cfg(feature = "std")]
+pub fn vu64_to_leb128<R: Read, W: Write>(mut reader: R, mut writer: W) -> IoResult<u64> {
+    let mut count = 0;
+    while let Some(first) = read_first(&mut reader)? {
+        let n = (&[first][..]).chain(&mut reader).read_vu64()?;
+        writer.write_uleb128(n)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+/// Extension trait for reading LEB128-encoded integers from an async reader.
+pub trait AsyncReadLeb128Ext {
+    /// Read an unsigned LEB128 `u64` asynchronously.
+    fn read_uleb128(&mut self) -> impl core::future::Future<Output = std::io::Result<u64>>;
+    /// Read a signed LEB128 `i64` asynchronously.
+    fn read_sleb128(&mut self) -> impl core::future::Future<Output = std::io::Result<i64>>;
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+/// Extension trait for writing LEB128-encoded integers to an async writer.
+pub trait AsyncWriteLeb128Ext {
+    /// Write an unsigned LEB128 `u64` asynchronously.
+    fn write_uleb128(&mut self, n: u64) -> impl core::future::Future<Output = std::io::Result<()>>;
+    /// Write a signed LEB128 `i64` asynchronously.
+    fn write_sleb128(&mut self, n: i64) -> impl core::future::Future<Output = std::io::Result<()>>;
+}
+
+#[cfg(feature = "async-futures")]
+mod futures_impl {
+    use futures_io::{AsyncRead, AsyncWrite};
+    use futures_util::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    /// Read the bytes of one encoding, stopping at the last byte or after the maximum
+    /// length.
+    async fn read_encoding<R: AsyncRead + Unpin>(
+        reader: &mut R,
+    ) -> std::io::Result<[u8; LEB128_BUF_SIZE]> {
+        let mut buf = [0u8; LEB128_BUF_SIZE];
+        AsyncReadExt::read_exact(reader, &mut buf[0..1]).await?;
+        let mut i = 0;
+        while buf[i] & 0x80 != 0 && i < LEB128_BUF_SIZE - 1 {
+            i += 1;
+            AsyncReadExt::read_exact(reader, &mut buf[i..i + 1]).await?;
+        }
+        Ok(buf)
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncReadLeb128Ext for R {
+        async fn read_uleb128(&mut self) -> std::io::Result<u64> {
+            Ok(decode_uleb128(&read_encoding(self).await?)?.0)
+        }
+
+        async fn read_sleb128(&mut self) -> std::io::Result<i64> {
+            Ok(decode_sleb128(&read_encoding(self).await?)?.0)
+        }
+    }
+
+    impl<W: AsyncWrite + Unpin> AsyncWriteLeb128Ext for W {
+        async fn write_uleb128(&mut self, n: u64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_uleb128(n).as_slice()).await
+        }
+
+        async fn write_sleb128(&mut self, n: i64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_sleb128(n).as_slice()).await
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+mod tokio_impl {
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+    use super::*;
+
+    /// Read the bytes of one encoding, stopping at the last byte or after the maximum
+    /// length.
+    async fn read_encoding<R: AsyncRead + Unpin>(
+        reader: &mut R,
+    ) -> std::io::Result<[u8; LEB128_BUF_SIZE]> {
+        let mut buf = [0u8; LEB128_BUF_SIZE];
+        AsyncReadExt::read_exact(reader, &mut buf[0..1]).await?;
+        let mut i = 0;
+        while buf[i] & 0x80 != 0 && i < LEB128_BUF_SIZE - 1 {
+            i += 1;
+            AsyncReadExt::read_exact(reader, &mut buf[i..i + 1]).await?;
+        }
+        Ok(buf)
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncReadLeb128Ext for R {
+        async fn read_uleb128(&mut self) -> std::io::Result<u64> {
+            Ok(decode_uleb128(&read_encoding(self).await?)?.0)
+        }
+
+        async fn read_sleb128(&mut self) -> std::io::Result<i64> {
+            Ok(decode_sleb128(&read_encoding(self).await?)?.0)
+        }
+    }
+
+    impl<W: AsyncWrite + Unpin> AsyncWriteLeb128Ext for W {
+        async fn write_uleb128(&mut self, n: u64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_uleb128(n).as_slice()).await
+        }
+
+        async fn write_sleb128(&mut self, n: i64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_sleb128(n).as_slice()).await
+        }
+    }
+}
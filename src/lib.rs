@@ -7,12 +7,38 @@
 //! - `Vu32` / `Vi32`: unsigned/signed 32-bit (max 5 bytes)
 //! - `Vu64` / `Vi64`: unsigned/signed 64-bit (max 9 bytes)
 //! - `Vu128` / `Vi128`: unsigned/signed 128-bit (max 18 bytes)
+//! - `Vu128V2`: unsigned 128-bit, revised layout (max 17 bytes)
+//! - `Vu256` / `Vi256`: unsigned/signed 256-bit (max 35 bytes)
+//! - `Vf32` / `Vf64`: 32/64-bit floating point (max 5/9 bytes)
+//! - `VuBig`: unsigned, arbitrary precision (requires `std`)
+//! - `VDecimal`: `rust_decimal::Decimal` as a scale and mantissa (requires `rust_decimal`)
 //!
 //! Signed types use zigzag encoding for efficient storage of small absolute values.
 //!
-//! This crate does not enforce an invariant that a number may only have one representation,
-//! which means that it is possible to encode `1` as, for example, both `0b1000_0001` and
-//! `0b0100_0000_0000_0001`.
+//! Each length class is offset by the capacity of the classes below it, so `Vu32` and
+//! `Vu64` have exactly one encoding per value. Encodings whose payload goes past the
+//! type's maximum fail to decode with [`DecodeError::Overflow`], as does a `Vu32` whose
+//! first byte is below `0x08`. `Vu128` is the exception: its 18-byte raw form can hold
+//! any value, including those with shorter encodings. `Vu128V2` has one encoding per
+//! value, and [`vu128_v1_to_v2`] converts stored `Vu128` data to it.
+//!
+//! The integer types always hold the shortest encoding of their value, whether built with
+//! `new` or from received bytes with `from_bytes` or `from_array`, which reject anything
+//! else. They compare and hash equal exactly when their values are equal, and order by
+//! value, so encoded values can be stored and forwarded without decoding them.
+//!
+//! ## Widening
+//!
+//! A field can be widened in a file format without rewriting existing data:
+//! - every `Vu32` encoding is the `Vu64` and `Vu128` encoding of the same value;
+//! - every `Vu64` encoding of a value below 72624976668147840 (at most 8 bytes) is the
+//!   `Vu128` encoding of the same value. The 9-byte `Vu64` form only carries over for
+//!   values from 9295997013522923648 up, where its second byte is at least `0x80`;
+//! - the same holds for `Vi32`, `Vi64` and `Vi128`, as zigzag maps a value to the same
+//!   unsigned number at every width.
+//!
+//! These are part of the format and will not change. `From` converts between the types
+//! by copying bytes where the layouts agree, and `TryFrom` narrows with a range check.
 //!
 //! ## Usage
 //!
@@ -27,36 +53,103 @@
 #![allow(clippy::cast_lossless)]
 #![deny(missing_docs)]
 
+mod bytes;
+mod cmp;
+mod codec;
+pub mod compact_size;
+#[cfg(feature = "embedded-io")]
+mod embedded;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_async;
+mod error;
 #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
 mod ext;
 #[cfg(feature = "async-futures")]
 mod futures;
+pub mod leb128;
+pub mod quic;
+#[cfg(feature = "std")]
+mod serialize;
+mod skip;
+pub mod sqlite4;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
 #[cfg(feature = "async-tokio")]
 mod tokio;
 
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+mod time;
+mod vf32;
+mod vf64;
 mod vi128;
+mod vi256;
 mod vi32;
 mod vi64;
 mod vu128;
+mod vu128_v2;
+mod vu256;
 mod vu32;
 mod vu64;
+#[cfg(feature = "std")]
+mod vubig;
 
 #[cfg(feature = "std")]
-use std::io::{Read, Result as IoResult, Write};
+use std::io::{BufRead, Read, Result as IoResult, Write};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime};
 
+pub use bytes::{decode_vbytes, decode_vstr};
+pub use cmp::{
+    cmp_encoded_vi32, cmp_encoded_vi64, cmp_encoded_vi128, cmp_encoded_vu32, cmp_encoded_vu64,
+    cmp_encoded_vu128, cmp_encoded_vu128_v2,
+};
+pub use codec::{
+    FixedBe, FixedBeN, FixedLe, FixedLeN, IntCodec, Leb128Codec, MAX_CODEC_LEN, Vu32Codec,
+    Vu64Codec, Vu128Codec, Vu128V2Codec,
+};
+#[cfg(feature = "rust_decimal")]
+pub use decimal::{VDecimal, decode_vdecimal, encode_vdecimal};
+pub use error::{DecodeError, EncodeError};
+#[cfg(feature = "derive")]
+pub use fastvlq_derive::{VlqDecode, VlqEncode};
+#[cfg(feature = "std")]
+pub use serialize::{VlqDecode, VlqEncode};
+pub use skip::{skip_vu64, skip_vu128};
+pub use time::{VDuration, decode_duration, encode_duration};
+pub use vf32::{Vf32, decode_vf32, encode_vf32};
+pub use vf64::{Vf64, decode_vf64, encode_vf64};
 pub use vi32::{Vi32, decode_vi32, encode_vi32};
 pub use vi64::{Vi64, decode_vi64, encode_vi64};
 pub use vi128::{Vi128, decode_vi128, encode_vi128};
+pub use vi256::{Vi256, decode_vi256, encode_vi256};
 pub use vu32::{Vu32, decode_vu32, encode_vu32};
 pub use vu64::{Vu64, decode_vu64, encode_vu64};
 pub use vu128::{Vu128, decode_vu128, encode_vu128};
+pub use vu128_v2::{Vu128V2, decode_vu128_v2, encode_vu128_v2, vu128_v1_to_v2};
+pub use vu256::{Vu256, decode_vu256, encode_vu256};
+#[cfg(feature = "std")]
+pub use vubig::{VuBig, decode_big, encode_big};
 
 #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
 pub use ext::{AsyncReadVlqExt, AsyncWriteVlqExt};
 
+#[cfg(feature = "embedded-io")]
+pub use embedded::{EmbeddedReadVlqExt, EmbeddedWriteVlqExt};
+#[cfg(feature = "embedded-io-async")]
+pub use embedded_async::{EmbeddedAsyncReadVlqExt, EmbeddedAsyncWriteVlqExt};
+#[cfg(feature = "embedded-io")]
+pub use error::ReadError;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::serialize::FixedInt;
+}
+
 #[cfg(feature = "std")]
 /// Extension trait for reading VLQ-encoded integers from a reader.
 pub trait ReadVlqExt {
@@ -72,6 +165,41 @@ pub trait ReadVlqExt {
     fn read_vu128(&mut self) -> IoResult<u128>;
     /// Read a variable-length `i128`.
     fn read_vi128(&mut self) -> IoResult<i128>;
+    /// Read a variable-length `u128` in the v2 layout.
+    fn read_vu128_v2(&mut self) -> IoResult<u128>;
+    /// Read a variable-length big-endian `u256`.
+    fn read_vu256(&mut self) -> IoResult<[u8; 32]>;
+    /// Read a variable-length big-endian two's complement `i256`.
+    fn read_vi256(&mut self) -> IoResult<[u8; 32]>;
+    /// Read a variable-length `f32`.
+    fn read_vf32(&mut self) -> IoResult<f32>;
+    /// Read a variable-length `f64`.
+    fn read_vf64(&mut self) -> IoResult<f64>;
+    /// Read a variable-length `Decimal`, rejecting scales above 28.
+    #[cfg(feature = "rust_decimal")]
+    fn read_vdecimal(&mut self) -> IoResult<rust_decimal::Decimal>;
+    /// Read a `Duration` as whole seconds and subsecond nanoseconds.
+    fn read_duration(&mut self) -> IoResult<Duration>;
+    /// Read a `SystemTime` as signed seconds since the Unix epoch and subsecond
+    /// nanoseconds.
+    fn read_system_time(&mut self) -> IoResult<SystemTime>;
+    /// Read an arbitrary-precision integer as a big-endian magnitude without leading
+    /// zeros. Long forms of more than `max_len` bytes are rejected.
+    fn read_vubig(&mut self, max_len: usize) -> IoResult<Vec<u8>>;
+    /// Read a length-prefixed byte string of at most `max_len` bytes.
+    fn read_vbytes(&mut self, max_len: usize) -> IoResult<Vec<u8>>;
+    /// Read a length-prefixed UTF-8 string of at most `max_len` bytes.
+    fn read_vstring(&mut self, max_len: usize) -> IoResult<String>;
+    /// Read a `u64` in the encoding given by the codec `C`.
+    fn read_u64_with<C: IntCodec>(&mut self) -> IoResult<u64>;
+    /// Skip a variable-length `u64` or `i64` without decoding it, returning its length.
+    fn skip_vu64(&mut self) -> IoResult<usize>;
+    /// Skip a variable-length `u128` or `i128` without decoding it, returning its length.
+    fn skip_vu128(&mut self) -> IoResult<usize>;
+    /// Skip `count` variable-length `u64`s or `i64`s, returning the total bytes skipped.
+    fn skip_n_vu64(&mut self, count: usize) -> IoResult<usize>;
+    /// Skip `count` variable-length `u128`s or `i128`s, returning the total bytes skipped.
+    fn skip_n_vu128(&mut self, count: usize) -> IoResult<usize>;
 }
 
 #[cfg(feature = "std")]
@@ -89,6 +217,53 @@ pub trait WriteVlqExt {
     fn write_vu128(&mut self, n: u128) -> IoResult<()>;
     /// Write a variable-length `i128`.
     fn write_vi128(&mut self, n: i128) -> IoResult<()>;
+    /// Write a variable-length `u128` in the v2 layout.
+    fn write_vu128_v2(&mut self, n: u128) -> IoResult<()>;
+    /// Write a variable-length big-endian `u256`.
+    fn write_vu256(&mut self, n: [u8; 32]) -> IoResult<()>;
+    /// Write a variable-length big-endian two's complement `i256`.
+    fn write_vi256(&mut self, n: [u8; 32]) -> IoResult<()>;
+    /// Write a variable-length `f32`.
+    fn write_vf32(&mut self, n: f32) -> IoResult<()>;
+    /// Write a variable-length `f64`.
+    fn write_vf64(&mut self, n: f64) -> IoResult<()>;
+    /// Write a variable-length `Decimal`.
+    #[cfg(feature = "rust_decimal")]
+    fn write_vdecimal(&mut self, n: rust_decimal::Decimal) -> IoResult<()>;
+    /// Write a `Duration` as whole seconds and subsecond nanoseconds.
+    fn write_duration(&mut self, d: Duration) -> IoResult<()>;
+    /// Write a `SystemTime` as signed seconds since the Unix epoch and subsecond
+    /// nanoseconds.
+    fn write_system_time(&mut self, t: SystemTime) -> IoResult<()>;
+    /// Write an arbitrary-precision integer from its big-endian magnitude.
+    fn write_vubig(&mut self, magnitude: &[u8]) -> IoResult<()>;
+    /// Write a length-prefixed byte string.
+    fn write_vbytes(&mut self, bytes: &[u8]) -> IoResult<()>;
+    /// Write a length-prefixed UTF-8 string.
+    fn write_vstr(&mut self, s: &str) -> IoResult<()>;
+    /// Write a `u64` in the encoding given by the codec `C`.
+    fn write_u64_with<C: IntCodec>(&mut self, n: u64) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for reading VLQ-encoded integers from a buffered reader.
+///
+/// When the reader's buffer already holds the whole encoding, the value is decoded
+/// straight out of [`BufRead::fill_buf`] and consumed in one step. Encodings that
+/// straddle a buffer boundary fall back to the [`ReadVlqExt`] implementation.
+pub trait BufReadVlqExt {
+    /// Read a variable-length `u32` from the reader's buffer.
+    fn read_vu32_buffered(&mut self) -> IoResult<u32>;
+    /// Read a variable-length `i32` from the reader's buffer.
+    fn read_vi32_buffered(&mut self) -> IoResult<i32>;
+    /// Read a variable-length `u64` from the reader's buffer.
+    fn read_vu64_buffered(&mut self) -> IoResult<u64>;
+    /// Read a variable-length `i64` from the reader's buffer.
+    fn read_vi64_buffered(&mut self) -> IoResult<i64>;
+    /// Read a variable-length `u128` from the reader's buffer.
+    fn read_vu128_buffered(&mut self) -> IoResult<u128>;
+    /// Read a variable-length `i128` from the reader's buffer.
+    fn read_vi128_buffered(&mut self) -> IoResult<i128>;
 }
 
 #[cfg(feature = "std")]
@@ -100,7 +275,9 @@ impl<R: Read> ReadVlqExt for R {
         if len > 1 {
             self.read_exact(&mut buf[1..len])?;
         }
-        Ok(decode_vu32(vu32::Vu32(buf)))
+        let vlq = vu32::Vu32(buf);
+        vlq.validate()?;
+        Ok(decode_vu32(vlq))
     }
 
     fn read_vi32(&mut self) -> IoResult<i32> {
@@ -114,7 +291,9 @@ impl<R: Read> ReadVlqExt for R {
         if len > 1 {
             self.read_exact(&mut buf[1..len])?;
         }
-        Ok(decode_vu64(vu64::Vu64(buf)))
+        let vlq = vu64::Vu64(buf);
+        vlq.validate()?;
+        Ok(decode_vu64(vlq))
     }
 
     fn read_vi64(&mut self) -> IoResult<i64> {
@@ -125,15 +304,15 @@ impl<R: Read> ReadVlqExt for R {
         let mut buf = [0u8; vu128::VU128_BUF_SIZE];
         self.read_exact(&mut buf[0..1])?;
         // Need second byte to determine extended length
-        if buf[0] == 0 {
+        let read = if buf[0] == 0 {
             self.read_exact(&mut buf[1..2])?;
-        }
+            2
+        } else {
+            1
+        };
         let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
-        if len > 2 {
-            self.read_exact(&mut buf[2..len])?;
-        } else if len == 2 && buf[0] != 0 {
-            // Standard 2-byte (not extended), already read first byte
-            self.read_exact(&mut buf[1..2])?;
+        if len > read {
+            self.read_exact(&mut buf[read..len])?;
         }
         Ok(decode_vu128(vu128::Vu128(buf)))
     }
@@ -141,6 +320,218 @@ impl<R: Read> ReadVlqExt for R {
     fn read_vi128(&mut self) -> IoResult<i128> {
         self.read_vu128().map(vi128::zigzag_decode_i128)
     }
+
+    fn read_vu128_v2(&mut self) -> IoResult<u128> {
+        let mut buf = [0u8; vu128_v2::VU128_V2_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        // Need second byte to determine extended length
+        let read = if buf[0] == 1 {
+            self.read_exact(&mut buf[1..2])?;
+            2
+        } else {
+            1
+        };
+        let len = vu128_v2::decode_len_vu128_v2(buf[0], buf[1]) as usize;
+        if len > read {
+            self.read_exact(&mut buf[read..len])?;
+        }
+        let vlq = Vu128V2(buf);
+        vlq.validate()?;
+        Ok(decode_vu128_v2(vlq))
+    }
+
+    fn read_vu256(&mut self) -> IoResult<[u8; 32]> {
+        let mut buf = [0u8; vu256::VU256_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        // Need second and third bytes to determine extended length
+        let mut read = 1;
+        if buf[0] == 0 {
+            self.read_exact(&mut buf[1..2])?;
+            read = 2;
+        }
+        let len = if buf[0] == 0 && buf[1] == 0 {
+            self.read_exact(&mut buf[2..3])?;
+            read = 3;
+            vu256::decode_long_len_vu256(buf[2])?
+        } else {
+            vu256::decode_len_vu256(buf[0], buf[1], buf[2])
+        } as usize;
+        if len > read {
+            self.read_exact(&mut buf[read..len])?;
+        }
+        Ok(decode_vu256(Vu256(buf)))
+    }
+
+    fn read_vi256(&mut self) -> IoResult<[u8; 32]> {
+        self.read_vu256().map(vi256::zigzag_decode_i256)
+    }
+
+    fn read_vf32(&mut self) -> IoResult<f32> {
+        self.read_vu32().map(vf32::float_decode_f32)
+    }
+
+    fn read_vf64(&mut self) -> IoResult<f64> {
+        self.read_vu64().map(vf64::float_decode_f64)
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    fn read_vdecimal(&mut self) -> IoResult<rust_decimal::Decimal> {
+        let scale = decimal::check_scale(self.read_vu32()?)?;
+        let mantissa = self.read_vi128()?;
+        Ok(decimal::from_parts(mantissa, scale)?)
+    }
+
+    fn read_duration(&mut self) -> IoResult<Duration> {
+        let secs = self.read_vu64()?;
+        let nanos = time::check_nanos(self.read_vu32()?)?;
+        Ok(Duration::new(secs, nanos))
+    }
+
+    fn read_system_time(&mut self) -> IoResult<SystemTime> {
+        let secs = self.read_vi64()?;
+        let nanos = time::check_nanos(self.read_vu32()?)?;
+        Ok(time::join_system_time(secs, nanos)?)
+    }
+
+    fn read_vubig(&mut self, max_len: usize) -> IoResult<Vec<u8>> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let read = if buf[0] == 0 {
+            self.read_exact(&mut buf[1..2])?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]);
+        if len <= vubig::SHORT_MAX_LEN {
+            let len = len as usize;
+            if len > read {
+                self.read_exact(&mut buf[read..len])?;
+            }
+            return Ok(vubig::short_magnitude(Vu128(buf)));
+        }
+
+        let magnitude = self.read_vbytes(max_len)?;
+        Ok(vubig::trim_magnitude(&magnitude).to_vec())
+    }
+
+    fn read_vbytes(&mut self, max_len: usize) -> IoResult<Vec<u8>> {
+        let len = bytes::check_len(self.read_vu64()?, max_len)?;
+        // Grow with the data actually read rather than trusting the prefix up front
+        let mut buf = Vec::new();
+        self.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            return Err(DecodeError::UnexpectedEof.into());
+        }
+        Ok(buf)
+    }
+
+    fn read_vstring(&mut self, max_len: usize) -> IoResult<String> {
+        let buf = self.read_vbytes(max_len)?;
+        String::from_utf8(buf).map_err(|e| DecodeError::InvalidUtf8(e.utf8_error()).into())
+    }
+
+    fn read_u64_with<C: IntCodec>(&mut self) -> IoResult<u64> {
+        let mut buf = [0u8; MAX_CODEC_LEN];
+        let mut read = 0;
+        let mut len = 1;
+        // Grow the prefix until the codec knows the full length
+        while len > read {
+            if len > MAX_CODEC_LEN {
+                return Err(DecodeError::LengthLimitExceeded {
+                    len: len as u64,
+                    max: MAX_CODEC_LEN,
+                }
+                .into());
+            }
+            self.read_exact(&mut buf[read..len])?;
+            read = len;
+            len = C::len_from_prefix(&buf[..read]);
+        }
+        Ok(C::decode_u64(&buf[..read])?.0)
+    }
+
+    fn skip_vu64(&mut self) -> IoResult<usize> {
+        // The remainder is at most 8 bytes, so discarding beats seeking
+        let mut buf = [0u8; vu64::VU64_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let len = vu64::decode_len_vu64(buf[0]) as usize;
+        if len > 1 {
+            self.read_exact(&mut buf[1..len])?;
+        }
+        Ok(len)
+    }
+
+    fn skip_vu128(&mut self) -> IoResult<usize> {
+        let mut buf = [0u8; vu128::VU128_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let read = if buf[0] == 0 {
+            self.read_exact(&mut buf[1..2])?;
+            2
+        } else {
+            1
+        };
+        let len = vu128::decode_len_vu128(buf[0], buf[1]) as usize;
+        if len > read {
+            self.read_exact(&mut buf[read..len])?;
+        }
+        Ok(len)
+    }
+
+    fn skip_n_vu64(&mut self, count: usize) -> IoResult<usize> {
+        let mut total = 0;
+        for _ in 0..count {
+            total += self.skip_vu64()?;
+        }
+        Ok(total)
+    }
+
+    fn skip_n_vu128(&mut self, count: usize) -> IoResult<usize> {
+        let mut total = 0;
+        for _ in 0..count {
+            total += self.skip_vu128()?;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> BufReadVlqExt for R {
+    fn read_vu32_buffered(&mut self) -> IoResult<u32> {
+        if let Ok((vlq, len)) = vu32::split_vu32(self.fill_buf()?) {
+            self.consume(len);
+            return Ok(decode_vu32(vlq));
+        }
+        self.read_vu32()
+    }
+
+    fn read_vi32_buffered(&mut self) -> IoResult<i32> {
+        self.read_vu32_buffered().map(vi32::zigzag_decode_i32)
+    }
+
+    fn read_vu64_buffered(&mut self) -> IoResult<u64> {
+        if let Ok((vlq, len)) = vu64::split_vu64(self.fill_buf()?) {
+            self.consume(len);
+            return Ok(decode_vu64(vlq));
+        }
+        self.read_vu64()
+    }
+
+    fn read_vi64_buffered(&mut self) -> IoResult<i64> {
+        self.read_vu64_buffered().map(vi64::zigzag_decode_i64)
+    }
+
+    fn read_vu128_buffered(&mut self) -> IoResult<u128> {
+        if let Some((vlq, len)) = vu128::split_vu128(self.fill_buf()?) {
+            self.consume(len);
+            return Ok(decode_vu128(vlq));
+        }
+        self.read_vu128()
+    }
+
+    fn read_vi128_buffered(&mut self) -> IoResult<i128> {
+        self.read_vu128_buffered().map(vi128::zigzag_decode_i128)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -168,4 +559,58 @@ impl<W: Write> WriteVlqExt for W {
     fn write_vi128(&mut self, n: i128) -> IoResult<()> {
         self.write_vu128(vi128::zigzag_encode_i128(n))
     }
+
+    fn write_vu128_v2(&mut self, n: u128) -> IoResult<()> {
+        self.write_all(encode_vu128_v2(n).as_slice())
+    }
+
+    fn write_vu256(&mut self, n: [u8; 32]) -> IoResult<()> {
+        self.write_all(encode_vu256(n).as_slice())
+    }
+
+    fn write_vi256(&mut self, n: [u8; 32]) -> IoResult<()> {
+        self.write_vu256(vi256::zigzag_encode_i256(n))
+    }
+
+    fn write_vf32(&mut self, n: f32) -> IoResult<()> {
+        self.write_vu32(vf32::float_encode_f32(n))
+    }
+
+    fn write_vf64(&mut self, n: f64) -> IoResult<()> {
+        self.write_vu64(vf64::float_encode_f64(n))
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    fn write_vdecimal(&mut self, n: rust_decimal::Decimal) -> IoResult<()> {
+        self.write_all(encode_vdecimal(n).as_slice())
+    }
+
+    fn write_duration(&mut self, d: Duration) -> IoResult<()> {
+        self.write_all(encode_duration(d).as_slice())
+    }
+
+    fn write_system_time(&mut self, t: SystemTime) -> IoResult<()> {
+        let (secs, nanos) = time::split_system_time(t).ok_or(EncodeError::Overflow)?;
+        self.write_vi64(secs)?;
+        self.write_vu32(nanos)
+    }
+
+    fn write_vubig(&mut self, magnitude: &[u8]) -> IoResult<()> {
+        self.write_all(encode_big(magnitude).as_slice())
+    }
+
+    fn write_vbytes(&mut self, bytes: &[u8]) -> IoResult<()> {
+        self.write_vu64(bytes.len() as u64)?;
+        self.write_all(bytes)
+    }
+
+    fn write_vstr(&mut self, s: &str) -> IoResult<()> {
+        self.write_vbytes(s.as_bytes())
+    }
+
+    fn write_u64_with<C: IntCodec>(&mut self, n: u64) -> IoResult<()> {
+        let mut buf = [0u8; MAX_CODEC_LEN];
+        let len = C::encode_u64(n, &mut buf)?;
+        self.write_all(&buf[..len])
+    }
 }
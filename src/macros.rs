@@ -176,3 +176,84 @@ macro_rules! copy_from_slice_offset {
         }
     };
 }
+
+/// Comparison, hashing, parsing and conversion impls shared by the integer types.
+///
+/// The types only ever hold the canonical encoding of their value, so equality and
+/// hashing use the encoded bytes directly. Ordering is by value.
+macro_rules! impl_int_traits {
+    ($ty:ident($native:ty), from: [$($from:ty),*], try_from: [$($try_from:ty),*]) => {
+        impl PartialEq for $ty {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::hash::Hash for $ty {
+            #[inline(always)]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.as_slice().hash(state);
+            }
+        }
+
+        impl PartialOrd for $ty {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $ty {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl Default for $ty {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl AsRef<[u8]> for $ty {
+            #[inline(always)]
+            fn as_ref(&self) -> &[u8] {
+                self.as_slice()
+            }
+        }
+
+        impl core::str::FromStr for $ty {
+            type Err = core::num::ParseIntError;
+
+            #[inline(always)]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse::<$native>().map(Self::new)
+            }
+        }
+
+        $(
+            impl From<$from> for $ty {
+                #[inline(always)]
+                fn from(n: $from) -> Self {
+                    Self::new(<$native>::from(n))
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for $ty {
+                type Error = core::num::TryFromIntError;
+
+                #[inline(always)]
+                fn try_from(n: $try_from) -> Result<Self, Self::Error> {
+                    <$native>::try_from(n).map(Self::new)
+                }
+            }
+        )*
+    };
+}
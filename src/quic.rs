@@ -0,0 +1,282 @@
+//! QUIC variable-length integer interop.
+//!
+//! QUIC varints (RFC 9000, section 16) store the length in the top two bits of the
+//! first byte, followed by a big-endian value in 1, 2, 4 or 8 bytes:
+//!
+//! ```text
+//! 00xx_xxxx                  1 byte,   6 bits
+//! 01xx_xxxx ...              2 bytes, 14 bits
+//! 10xx_xxxx ...              4 bytes, 30 bits
+//! 11xx_xxxx ...              8 bytes, 62 bits
+//! ```
+//!
+//! Values above [`QuicVarInt::MAX`] cannot be encoded, so encoding one, or converting a
+//! `Vu64` holding one, fails with [`EncodeError::Overflow`].
+
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::io::{Read, Result as IoResult, Write};
+
+use crate::error::{DecodeError, EncodeError};
+use crate::vu64::{Vu64, decode_vu64, encode_vu64};
+
+pub(crate) const QUIC_BUF_SIZE: usize = 8;
+
+/// Decode length from the first byte.
+#[inline(always)]
+pub(crate) const fn decode_len_quic(first: u8) -> u8 {
+    1 << (first >> 6)
+}
+
+/// Encode a u64 as a QUIC varint, failing if it is above [`QuicVarInt::MAX`].
+#[inline(always)]
+pub const fn encode_quic_varint(n: u64) -> Result<QuicVarInt, EncodeError> {
+    let (len, tag) = if n < 1 << 6 {
+        (1, 0x00)
+    } else if n < 1 << 14 {
+        (2, 0x40)
+    } else if n < 1 << 30 {
+        (4, 0x80)
+    } else if n <= QuicVarInt::MAX {
+        (8, 0xC0)
+    } else {
+        return Err(EncodeError::Overflow);
+    };
+
+    let mut out_buf = [0u8; QUIC_BUF_SIZE];
+    let buf = n.to_be_bytes();
+    let mut i = 0;
+    while i < len {
+        out_buf[i] = buf[QUIC_BUF_SIZE - len + i];
+        i += 1;
+    }
+    out_buf[0] |= tag;
+    Ok(QuicVarInt(out_buf))
+}
+
+#[inline(always)]
+const fn quic_value(n: &QuicVarInt) -> u64 {
+    let len = n.len() as usize;
+    let mut out = (n.0[0] & 0x3F) as u64;
+    let mut i = 1;
+    while i < len {
+        out = (out << 8) | n.0[i] as u64;
+        i += 1;
+    }
+    out
+}
+
+/// Copy a complete encoding off the front of `buf`, along with its length in bytes.
+#[inline(always)]
+fn split_quic_varint(buf: &[u8]) -> Option<(QuicVarInt, usize)> {
+    let len = decode_len_quic(*buf.first()?) as usize;
+    let mut out_buf = [0u8; QUIC_BUF_SIZE];
+    out_buf[..len].copy_from_slice(buf.get(..len)?);
+    Some((QuicVarInt(out_buf), len))
+}
+
+/// Decode a QUIC varint from the front of `buf`.
+///
+/// Returns the value and the number of bytes consumed.
+pub fn decode_quic_varint(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let (vlq, len) = split_quic_varint(buf).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((quic_value(&vlq), len))
+}
+
+/// An unsigned integer of up to 62 bits in QUIC variable-length integer encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct QuicVarInt(pub(crate) [u8; QUIC_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl QuicVarInt {
+    /// Largest value a QUIC varint can hold, 2^62 - 1.
+    pub const MAX: u64 = (1 << 62) - 1;
+
+    /// Maximum length of an encoded `QuicVarInt` in bytes.
+    pub const MAX_LEN: usize = QUIC_BUF_SIZE;
+
+    /// Construct a new QUIC varint from the given `u64`, failing if it is above
+    /// [`QuicVarInt::MAX`].
+    #[inline(always)]
+    pub const fn new(value: u64) -> Result<QuicVarInt, EncodeError> {
+        encode_quic_varint(value)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        decode_len_quic(first)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        decode_len_quic(self.0[0])
+    }
+
+    /// Retrieve the stored number as `u64`.
+    #[inline(always)]
+    pub const fn get(&self) -> u64 {
+        quic_value(self)
+    }
+
+    /// Get the raw byte representation of the QUIC varint.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Get the serialized representation of the QUIC varint as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl TryFrom<u64> for QuicVarInt {
+    type Error = EncodeError;
+
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        encode_quic_varint(n)
+    }
+}
+
+impl From<QuicVarInt> for u64 {
+    fn from(n: QuicVarInt) -> Self {
+        quic_value(&n)
+    }
+}
+
+impl TryFrom<Vu64> for QuicVarInt {
+    type Error = EncodeError;
+
+    fn try_from(n: Vu64) -> Result<Self, Self::Error> {
+        encode_quic_varint(decode_vu64(n))
+    }
+}
+
+impl From<QuicVarInt> for Vu64 {
+    fn from(n: QuicVarInt) -> Self {
+        encode_vu64(quic_value(&n))
+    }
+}
+
+impl Display for QuicVarInt {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for QuicVarInt {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "QuicVarInt(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for reading QUIC varints from a reader.
+pub trait ReadQuicExt {
+    /// Read a QUIC varint.
+    fn read_quic_varint(&mut self) -> IoResult<u64>;
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for writing QUIC varints to a writer.
+pub trait WriteQuicExt {
+    /// Write a QUIC varint, failing if `n` is above [`QuicVarInt::MAX`].
+    fn write_quic_varint(&mut self, n: u64) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadQuicExt for R {
+    fn read_quic_varint(&mut self) -> IoResult<u64> {
+        let mut buf = [0u8; QUIC_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let len = decode_len_quic(buf[0]) as usize;
+        if len > 1 {
+            self.read_exact(&mut buf[1..len])?;
+        }
+        Ok(quic_value(&QuicVarInt(buf)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> WriteQuicExt for W {
+    fn write_quic_varint(&mut self, n: u64) -> IoResult<()> {
+        self.write_all(encode_quic_varint(n)?.as_slice())
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+/// Extension trait for reading QUIC varints from an async reader.
+pub trait AsyncReadQuicExt {
+    /// Read a QUIC varint asynchronously.
+    fn read_quic_varint(&mut self) -> impl core::future::Future<Output = std::io::Result<u64>>;
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+/// Extension trait for writing QUIC varints to an async writer.
+pub trait AsyncWriteQuicExt {
+    /// Write a QUIC varint asynchronously, failing if `n` is above [`QuicVarInt::MAX`].
+    fn write_quic_varint(
+        &mut self,
+        n: u64,
+    ) -> impl core::future::Future<Output = std::io::Result<()>>;
+}
+
+#[cfg(feature = "async-futures")]
+mod futures_impl {
+    use futures_io::{AsyncRead, AsyncWrite};
+    use futures_util::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    impl<R: AsyncRead + Unpin> AsyncReadQuicExt for R {
+        async fn read_quic_varint(&mut self) -> std::io::Result<u64> {
+            let mut buf = [0u8; QUIC_BUF_SIZE];
+            AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+            let len = decode_len_quic(buf[0]) as usize;
+            if len > 1 {
+                AsyncReadExt::read_exact(self, &mut buf[1..len]).await?;
+            }
+            Ok(quic_value(&QuicVarInt(buf)))
+        }
+    }
+
+    impl<W: AsyncWrite + Unpin> AsyncWriteQuicExt for W {
+        async fn write_quic_varint(&mut self, n: u64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_quic_varint(n)?.as_slice()).await
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+mod tokio_impl {
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+    use super::*;
+
+    impl<R: AsyncRead + Unpin> AsyncReadQuicExt for R {
+        async fn read_quic_varint(&mut self) -> std::io::Result<u64> {
+            let mut buf = [0u8; QUIC_BUF_SIZE];
+            AsyncReadExt::read_exact(self, &mut buf[0..1]).await?;
+            let len = decode_len_quic(buf[0]) as usize;
+            if len > 1 {
+                AsyncReadExt::read_exact(self, &mut buf[1..len]).await?;
+            }
+            Ok(quic_value(&QuicVarInt(buf)))
+        }
+    }
+
+    impl<W: AsyncWrite + Unpin> AsyncWriteQuicExt for W {
+        async fn write_quic_varint(&mut self, n: u64) -> std::io::Result<()> {
+            AsyncWriteExt::write_all(self, encode_quic_varint(n)?.as_slice()).await
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Whole-value serialization.
+//!
+//! [`VlqEncode`] and [`VlqDecode`] write and read a value as a sequence of VLQ fields.
+//! They are implemented here for the primitive types, and can be derived for structs
+//! and enums with the `derive` feature.
+
+use std::io::{ErrorKind, Read, Result as IoResult, Write};
+
+use crate::error::{DecodeError, EncodeError};
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+use crate::ext::{AsyncReadVlqExt, AsyncWriteVlqExt};
+use crate::{ReadVlqExt, WriteVlqExt};
+
+/// Map an error from reading or writing a slice back to the decode error behind it.
+fn slice_error(e: std::io::Error) -> DecodeError {
+    if e.kind() == ErrorKind::UnexpectedEof {
+        return DecodeError::UnexpectedEof;
+    }
+    // Slices only fail on length, so anything else came from a nested decode
+    e.get_ref()
+        .and_then(|inner| inner.downcast_ref::<DecodeError>())
+        .copied()
+        .unwrap_or(DecodeError::Overflow)
+}
+
+/// Map an error from writing to a `Vec` back to the encode error behind it.
+fn vec_error(e: std::io::Error) -> EncodeError {
+    // Writing to a `Vec` cannot fail, so the error came from a value that did not fit
+    e.get_ref()
+        .and_then(|inner| inner.downcast_ref::<EncodeError>())
+        .copied()
+        .unwrap_or(EncodeError::Overflow)
+}
+
+/// A value that can be written as a sequence of VLQ fields.
+pub trait VlqEncode {
+    /// Write the value to a writer.
+    fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()>;
+
+    /// Write the value to an async writer.
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    fn write_vlq_async<W: AsyncWriteVlqExt>(
+        &self,
+        writer: &mut W,
+    ) -> impl core::future::Future<Output = IoResult<()>>;
+
+    /// Encode the value to a new buffer.
+    fn encode_vlq(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut buf = Vec::new();
+        self.write_vlq(&mut buf).map_err(vec_error)?;
+        Ok(buf)
+    }
+}
+
+/// A value that can be read as a sequence of VLQ fields.
+pub trait VlqDecode: Sized {
+    /// Read a value from a reader.
+    fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self>;
+
+    /// Read a value from an async reader.
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    fn read_vlq_async<R: AsyncReadVlqExt>(
+        reader: &mut R,
+    ) -> impl core::future::Future<Output = IoResult<Self>>;
+
+    /// Decode a value from the front of `buf`.
+    ///
+    /// Returns the value and the number of bytes consumed.
+    fn decode_vlq(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let mut reader = buf;
+        let value = Self::read_vlq(&mut reader).map_err(slice_error)?;
+        Ok((value, buf.len() - reader.len()))
+    }
+}
+
+macro_rules! impl_vlq_int {
+    ($($ty:ty => $read:ident, $write:ident, $wire:ty;)*) => {
+        $(
+            impl VlqEncode for $ty {
+                fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+                    let n = <$wire>::try_from(*self).map_err(|_| EncodeError::Overflow)?;
+                    WriteVlqExt::$write(writer, n)
+                }
+
+                #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+                async fn write_vlq_async<W: AsyncWriteVlqExt>(&self, writer: &mut W) -> IoResult<()> {
+                    let n = <$wire>::try_from(*self).map_err(|_| EncodeError::Overflow)?;
+                    AsyncWriteVlqExt::$write(writer, n).await
+                }
+            }
+
+            impl VlqDecode for $ty {
+                fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self> {
+                    let n = ReadVlqExt::$read(reader)?;
+                    Ok(<$ty>::try_from(n).map_err(|_| DecodeError::Overflow)?)
+                }
+
+                #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+                async fn read_vlq_async<R: AsyncReadVlqExt>(reader: &mut R) -> IoResult<Self> {
+                    let n = AsyncReadVlqExt::$read(reader).await?;
+                    Ok(<$ty>::try_from(n).map_err(|_| DecodeError::Overflow)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_vlq_int! {
+    u8 => read_vu32, write_vu32, u32;
+    u16 => read_vu32, write_vu32, u32;
+    u32 => read_vu32, write_vu32, u32;
+    u64 => read_vu64, write_vu64, u64;
+    usize => read_vu64, write_vu64, u64;
+    u128 => read_vu128, write_vu128, u128;
+    i8 => read_vi32, write_vi32, i32;
+    i16 => read_vi32, write_vi32, i32;
+    i32 => read_vi32, write_vi32, i32;
+    i64 => read_vi64, write_vi64, i64;
+    isize => read_vi64, write_vi64, i64;
+    i128 => read_vi128, write_vi128, i128;
+}
+
+macro_rules! impl_vlq_value {
+    ($($ty:ty => $read:ident, $write:ident;)*) => {
+        $(
+            impl VlqEncode for $ty {
+                fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+                    WriteVlqExt::$write(writer, *self)
+                }
+
+                #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+                async fn write_vlq_async<W: AsyncWriteVlqExt>(&self, writer: &mut W) -> IoResult<()> {
+                    AsyncWriteVlqExt::$write(writer, *self).await
+                }
+            }
+
+            impl VlqDecode for $ty {
+                fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self> {
+                    ReadVlqExt::$read(reader)
+                }
+
+                #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+                async fn read_vlq_async<R: AsyncReadVlqExt>(reader: &mut R) -> IoResult<Self> {
+                    AsyncReadVlqExt::$read(reader).await
+                }
+            }
+        )*
+    };
+}
+
+impl_vlq_value! {
+    f32 => read_vf32, write_vf32;
+    f64 => read_vf64, write_vf64;
+    core::time::Duration => read_duration, write_duration;
+    std::time::SystemTime => read_system_time, write_system_time;
+}
+
+/// Booleans are a `Vu32` of 0 or 1.
+#[inline(always)]
+fn bool_from_vu32(n: u32) -> Result<bool, DecodeError> {
+    match n {
+        0 => Ok(false),
+        1 => Ok(true),
+        n => Err(DecodeError::InvalidBool(n)),
+    }
+}
+
+impl VlqEncode for bool {
+    fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        WriteVlqExt::write_vu32(writer, *self as u32)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn write_vlq_async<W: AsyncWriteVlqExt>(&self, writer: &mut W) -> IoResult<()> {
+        AsyncWriteVlqExt::write_vu32(writer, *self as u32).await
+    }
+}
+
+impl VlqDecode for bool {
+    fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self> {
+        Ok(bool_from_vu32(ReadVlqExt::read_vu32(reader)?)?)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn read_vlq_async<R: AsyncReadVlqExt>(reader: &mut R) -> IoResult<Self> {
+        Ok(bool_from_vu32(AsyncReadVlqExt::read_vu32(reader).await?)?)
+    }
+}
+
+impl VlqEncode for String {
+    fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        WriteVlqExt::write_vstr(writer, self)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn write_vlq_async<W: AsyncWriteVlqExt>(&self, writer: &mut W) -> IoResult<()> {
+        AsyncWriteVlqExt::write_vstr(writer, self).await
+    }
+}
+
+impl VlqDecode for String {
+    fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self> {
+        ReadVlqExt::read_vstring(reader, usize::MAX)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn read_vlq_async<R: AsyncReadVlqExt>(reader: &mut R) -> IoResult<Self> {
+        AsyncReadVlqExt::read_vstring(reader, usize::MAX).await
+    }
+}
+
+impl VlqEncode for Vec<u8> {
+    fn write_vlq<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        WriteVlqExt::write_vbytes(writer, self)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn write_vlq_async<W: AsyncWriteVlqExt>(&self, writer: &mut W) -> IoResult<()> {
+        AsyncWriteVlqExt::write_vbytes(writer, self).await
+    }
+}
+
+impl VlqDecode for Vec<u8> {
+    fn read_vlq<R: Read>(reader: &mut R) -> IoResult<Self> {
+        ReadVlqExt::read_vbytes(reader, usize::MAX)
+    }
+
+    #[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+    async fn read_vlq_async<R: AsyncReadVlqExt>(reader: &mut R) -> IoResult<Self> {
+        AsyncReadVlqExt::read_vbytes(reader, usize::MAX).await
+    }
+}
+
+/// Integers that `#[vlq(fixed)]` can store at their native width.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub trait FixedInt: Copy {
+    /// The value's bits, zero-extended.
+    fn to_bits(self) -> u64;
+
+    /// Truncate bits back to the value.
+    fn from_bits(bits: u64) -> Self;
+}
+
+#[cfg(feature = "derive")]
+macro_rules! impl_fixed_int {
+    ($($ty:ty => $unsigned:ty;)*) => {
+        $(
+            impl FixedInt for $ty {
+                #[inline(always)]
+                fn to_bits(self) -> u64 {
+                    self as $unsigned as u64
+                }
+
+                #[inline(always)]
+                fn from_bits(bits: u64) -> Self {
+                    bits as $unsigned as $ty
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "derive")]
+impl_fixed_int! {
+    u8 => u8;
+    u16 => u16;
+    u32 => u32;
+    u64 => u64;
+    i8 => u8;
+    i16 => u16;
+    i32 => u32;
+    i64 => u64;
+}
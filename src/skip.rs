@@ -0,0 +1,45 @@
+//! Skipping over encoded values without decoding them.
+//!
+//! `Vu32` and `Vi32` encodings are skipped with the 64-bit functions, as their lengths
+//! are derived from the first byte in the same way.
+
+use crate::error::DecodeError;
+use crate::vu64::decode_len_vu64;
+use crate::vu128::decode_len_vu128;
+
+/// Skip `count` `Vu64` (or `Vi64`) encodings at the front of `buf`.
+///
+/// Only the first byte of each value is inspected. Returns the offset just past the
+/// last value skipped.
+pub fn skip_vu64(buf: &[u8], count: usize) -> Result<usize, DecodeError> {
+    let mut offset = 0;
+    for _ in 0..count {
+        let first = *buf.get(offset).ok_or(DecodeError::UnexpectedEof)?;
+        offset += decode_len_vu64(first) as usize;
+    }
+    if offset > buf.len() {
+        return Err(DecodeError::UnexpectedEof);
+    }
+    Ok(offset)
+}
+
+/// Skip `count` `Vu128` (or `Vi128`) encodings at the front of `buf`.
+///
+/// Only the first byte of each value is inspected, plus the second when the first is
+/// `0x00`. Returns the offset just past the last value skipped.
+pub fn skip_vu128(buf: &[u8], count: usize) -> Result<usize, DecodeError> {
+    let mut offset = 0;
+    for _ in 0..count {
+        let first = *buf.get(offset).ok_or(DecodeError::UnexpectedEof)?;
+        let second = if first == 0 {
+            *buf.get(offset + 1).ok_or(DecodeError::UnexpectedEof)?
+        } else {
+            0
+        };
+        offset += decode_len_vu128(first, second) as usize;
+    }
+    if offset > buf.len() {
+        return Err(DecodeError::UnexpectedEof);
+    }
+    Ok(offset)
+}
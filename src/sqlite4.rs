@@ -0,0 +1,217 @@
+//! SQLite4 varint interop.
+//!
+//! SQLite4 varints derive their length from the first byte `A0`, and small values take
+//! one or two bytes:
+//!
+//! ```text
+//! 0..=240      A0                               1 byte
+//! 241..=248    240 + 256 * (A0 - 241) + A1      2 bytes
+//! 249          2288 + 256 * A1 + A2             3 bytes
+//! 250..=255    A0 - 247 big-endian bytes        4 to 9 bytes
+//! ```
+
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::io::{Read, Result as IoResult, Write};
+
+use crate::error::DecodeError;
+
+pub(crate) const SQLITE4_BUF_SIZE: usize = 9;
+
+/// Decode length from the first byte.
+#[inline(always)]
+pub(crate) const fn decode_len_sqlite4(first: u8) -> u8 {
+    match first {
+        0..=240 => 1,
+        241..=248 => 2,
+        249 => 3,
+        _ => first - 246,
+    }
+}
+
+#[inline(always)]
+const fn encode_len_sqlite4(n: u64) -> u8 {
+    match n {
+        0..=240 => 1,
+        241..=2287 => 2,
+        2288..=67823 => 3,
+        _ => 9 - (n.leading_zeros() / 8) as u8,
+    }
+}
+
+/// Encode a u64 as a SQLite4 varint.
+#[inline(always)]
+#[must_use]
+pub const fn encode_sqlite4(n: u64) -> Sqlite4Varint {
+    let mut out_buf = [0u8; SQLITE4_BUF_SIZE];
+    let len = encode_len_sqlite4(n) as usize;
+    match len {
+        1 => out_buf[0] = n as u8,
+        2 => {
+            out_buf[0] = ((n - 240) / 256 + 241) as u8;
+            out_buf[1] = ((n - 240) % 256) as u8;
+        }
+        3 => {
+            out_buf[0] = 249;
+            out_buf[1] = ((n - 2288) / 256) as u8;
+            out_buf[2] = ((n - 2288) % 256) as u8;
+        }
+        _ => {
+            out_buf[0] = (len + 246) as u8;
+            let buf = n.to_be_bytes();
+            let mut i = 1;
+            while i < len {
+                out_buf[i] = buf[i + 8 - len];
+                i += 1;
+            }
+        }
+    }
+    Sqlite4Varint(out_buf)
+}
+
+#[inline(always)]
+const fn sqlite4_value(n: &Sqlite4Varint) -> u64 {
+    let b = n.0;
+    match b[0] {
+        0..=240 => b[0] as u64,
+        241..=248 => 240 + 256 * (b[0] as u64 - 241) + b[1] as u64,
+        249 => 2288 + 256 * b[1] as u64 + b[2] as u64,
+        _ => {
+            let len = n.len() as usize;
+            let mut out = 0u64;
+            let mut i = 1;
+            while i < len {
+                out = (out << 8) | b[i] as u64;
+                i += 1;
+            }
+            out
+        }
+    }
+}
+
+/// Decode a SQLite4 varint from the front of `buf`.
+///
+/// Returns the value and the number of bytes consumed.
+pub fn decode_sqlite4(buf: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let len = decode_len_sqlite4(*buf.first().ok_or(DecodeError::UnexpectedEof)?) as usize;
+    let mut out_buf = [0u8; SQLITE4_BUF_SIZE];
+    out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+    Ok((sqlite4_value(&Sqlite4Varint(out_buf)), len))
+}
+
+/// An unsigned 64-bit integer in SQLite4 varint encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Sqlite4Varint([u8; SQLITE4_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl Sqlite4Varint {
+    /// Maximum length of an encoded `Sqlite4Varint` in bytes.
+    pub const MAX_LEN: usize = SQLITE4_BUF_SIZE;
+
+    /// Construct a new SQLite4 varint from the given `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: u64) -> Sqlite4Varint {
+        encode_sqlite4(value)
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u64) -> u8 {
+        encode_len_sqlite4(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        decode_len_sqlite4(first)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        decode_len_sqlite4(self.0[0])
+    }
+
+    /// Retrieve the stored number as `u64`.
+    #[inline(always)]
+    pub const fn get(&self) -> u64 {
+        sqlite4_value(self)
+    }
+
+    /// Get the raw byte representation of the SQLite4 varint.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 9] {
+        self.0
+    }
+
+    /// Get the serialized representation of the SQLite4 varint as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<u64> for Sqlite4Varint {
+    fn from(n: u64) -> Self {
+        encode_sqlite4(n)
+    }
+}
+
+impl From<Sqlite4Varint> for u64 {
+    fn from(n: Sqlite4Varint) -> Self {
+        sqlite4_value(&n)
+    }
+}
+
+impl Display for Sqlite4Varint {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Sqlite4Varint {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Sqlite4Varint(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for reading SQLite4 varints from a reader.
+pub trait ReadSqlite4Ext {
+    /// Read a SQLite4 varint.
+    fn read_sqlite4(&mut self) -> IoResult<u64>;
+}
+
+#[cfg(feature = "std")]
+/// Extension trait for writing SQLite4 varints to a writer.
+pub trait WriteSqlite4Ext {
+    /// Write a SQLite4 varint.
+    fn write_sqlite4(&mut self, n: u64) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadSqlite4Ext for R {
+    fn read_sqlite4(&mut self) -> IoResult<u64> {
+        let mut buf = [0u8; SQLITE4_BUF_SIZE];
+        self.read_exact(&mut buf[0..1])?;
+        let len = decode_len_sqlite4(buf[0]) as usize;
+        if len > 1 {
+            self.read_exact(&mut buf[1..len])?;
+        }
+        Ok(sqlite4_value(&Sqlite4Varint(buf)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> WriteSqlite4Ext for W {
+    fn write_sqlite4(&mut self, n: u64) -> IoResult<()> {
+        self.write_all(encode_sqlite4(n).as_slice())
+    }
+}
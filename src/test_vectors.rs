@@ -0,0 +1,1563 @@
+//! Golden test vectors for checking other implementations against this one.
+//!
+//! The valid tables pair values with their canonical encodings at both ends of every length
+//! class, so a decoder that gets them all right has every prefix and offset right.
+//!
+//! The non-canonical table holds `Vu128` encodings that decode but are never produced,
+//! along with the value they decode to. Every other layout has exactly one encoding per
+//! value. The invalid tables hold inputs that fail to decode, along with the error. They
+//! are given per layout: signed and float types read the same bytes as the unsigned type
+//! of their width.
+//!
+//! [`to_json`] exports every table for use outside Rust.
+
+use core::fmt::Write;
+
+use crate::DecodeError;
+
+/// A value and its canonical encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<T: 'static> {
+    /// The value.
+    pub value: T,
+    /// Its canonical encoding.
+    pub encoding: &'static [u8],
+}
+
+/// An encoding that decodes, but that the encoder never produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonCanonicalVector<T: 'static> {
+    /// The non-canonical encoding.
+    pub encoding: &'static [u8],
+    /// The value it decodes to.
+    pub value: T,
+    /// The canonical encoding of `value`.
+    pub canonical: &'static [u8],
+}
+
+/// An input that fails to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidVector {
+    /// The input.
+    pub encoding: &'static [u8],
+    /// The error decoding it gives.
+    pub error: DecodeError,
+}
+
+/// `Vu32` values at both ends of each length class.
+pub const VU32: &[Vector<u32>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: 127,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 128,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: 16511,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 16512,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: 2113663,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 2113664,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: 270549119,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 270549120,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: u32::MAX,
+        encoding: b"\x08\xef\xdf\xbf\x7f",
+    },
+];
+
+/// `Vi32` values at both ends of each length class, and where each class changes sign.
+pub const VI32: &[Vector<i32>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: -1,
+        encoding: b"\x81",
+    },
+    Vector {
+        value: 63,
+        encoding: b"\xfe",
+    },
+    Vector {
+        value: -64,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 64,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: -65,
+        encoding: b"\x40\x01",
+    },
+    Vector {
+        value: 8255,
+        encoding: b"\x7f\xfe",
+    },
+    Vector {
+        value: -8256,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 8256,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: -8257,
+        encoding: b"\x20\x00\x01",
+    },
+    Vector {
+        value: 1056831,
+        encoding: b"\x3f\xff\xfe",
+    },
+    Vector {
+        value: -1056832,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 1056832,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: -1056833,
+        encoding: b"\x10\x00\x00\x01",
+    },
+    Vector {
+        value: 135274559,
+        encoding: b"\x1f\xff\xff\xfe",
+    },
+    Vector {
+        value: -135274560,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 135274560,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -135274561,
+        encoding: b"\x08\x00\x00\x00\x01",
+    },
+    Vector {
+        value: i32::MAX,
+        encoding: b"\x08\xef\xdf\xbf\x7e",
+    },
+    Vector {
+        value: i32::MIN,
+        encoding: b"\x08\xef\xdf\xbf\x7f",
+    },
+];
+
+/// `Vu64` values at both ends of each length class.
+pub const VU64: &[Vector<u64>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: 127,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 128,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: 16511,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 16512,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: 2113663,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 2113664,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: 270549119,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 270549120,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 34630287487,
+        encoding: b"\x0f\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 34630287488,
+        encoding: b"\x04\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 4432676798591,
+        encoding: b"\x07\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 4432676798592,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 567382630219903,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 567382630219904,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 72624976668147839,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 72624976668147840,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: u64::MAX,
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7f",
+    },
+];
+
+/// `Vi64` values at both ends of each length class, and where each class changes sign.
+pub const VI64: &[Vector<i64>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: -1,
+        encoding: b"\x81",
+    },
+    Vector {
+        value: 63,
+        encoding: b"\xfe",
+    },
+    Vector {
+        value: -64,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 64,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: -65,
+        encoding: b"\x40\x01",
+    },
+    Vector {
+        value: 8255,
+        encoding: b"\x7f\xfe",
+    },
+    Vector {
+        value: -8256,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 8256,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: -8257,
+        encoding: b"\x20\x00\x01",
+    },
+    Vector {
+        value: 1056831,
+        encoding: b"\x3f\xff\xfe",
+    },
+    Vector {
+        value: -1056832,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 1056832,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: -1056833,
+        encoding: b"\x10\x00\x00\x01",
+    },
+    Vector {
+        value: 135274559,
+        encoding: b"\x1f\xff\xff\xfe",
+    },
+    Vector {
+        value: -135274560,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 135274560,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -135274561,
+        encoding: b"\x08\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 17315143743,
+        encoding: b"\x0f\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -17315143744,
+        encoding: b"\x0f\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 17315143744,
+        encoding: b"\x04\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -17315143745,
+        encoding: b"\x04\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 2216338399295,
+        encoding: b"\x07\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -2216338399296,
+        encoding: b"\x07\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 2216338399296,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -2216338399297,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 283691315109951,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -283691315109952,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 283691315109952,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -283691315109953,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 36312488334073919,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -36312488334073920,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 36312488334073920,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -36312488334073921,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: i64::MAX,
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7e",
+    },
+    Vector {
+        value: i64::MIN,
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7f",
+    },
+];
+
+/// `Vu128` values at both ends of each run of equal lengths.
+///
+/// Values from the end of the 8-byte class up to the 9-byte class, and the upper part of
+/// each extended class, only fit the 18-byte raw form.
+pub const VU128: &[Vector<u128>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: 127,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 128,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: 16511,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 16512,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: 2113663,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 2113664,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: 270549119,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 270549120,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 34630287487,
+        encoding: b"\x0f\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 34630287488,
+        encoding: b"\x04\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 4432676798591,
+        encoding: b"\x07\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 4432676798592,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 567382630219903,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 567382630219904,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 72624976668147839,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 72624976668147840,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 9295997013522923647,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 9295997013522923648,
+        encoding: b"\x00\x80\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 18519369050377699455,
+        encoding: b"\x00\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 18519369050377699456,
+        encoding: b"\x00\x40\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 1199110989767789002879,
+        encoding: b"\x00\x7f\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 1199110989767789002880,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x41\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 2379702610485200306303,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 2379702610485200306304,
+        encoding: b"\x00\x20\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 153495430062313847144575,
+        encoding: b"\x00\x3f\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 153495430062313847144576,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x20\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 304611157514142493982847,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 304611157514142493982848,
+        encoding: b"\x00\x10\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 19647424271348209289281663,
+        encoding: b"\x00\x1f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 19647424271348209289281664,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x10\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 38990237385182276084580479,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 38990237385182276084580480,
+        encoding: b"\x00\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 2514870315955942825882828927,
+        encoding: b"\x00\x0f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 2514870315955942825882828928,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x08\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 4990750394526703375681077375,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 4990750394526703375681077376,
+        encoding: b"\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 321903400451584053749856878719,
+        encoding: b"\x00\x07\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 321903400451584053749856878720,
+        encoding: b"\x00\x00\x00\x00\x00\x04\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 638816050508641404124032680063,
+        encoding: b"\x00\x00\x00\x00\x00\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 638816050508641404124032680064,
+        encoding: b"\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 41203635257811982252018535252095,
+        encoding: b"\x00\x03\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 41203635257811982252018535252096,
+        encoding: b"\x00\x00\x00\x00\x02\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: 81768454465115323099913037824127,
+        encoding: b"\x00\x00\x00\x00\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 81768454465115323099913037824128,
+        encoding: b"\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 5274065312999942951630409367044223,
+        encoding: b"\x00\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 5274065312999942951630409367044224,
+        encoding: b"\x00\x00\x00\x01\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: u128::MAX,
+        encoding: b"\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+];
+
+/// `Vi128` values at both ends of each run of equal lengths, and where each run changes
+/// sign.
+pub const VI128: &[Vector<i128>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: -1,
+        encoding: b"\x81",
+    },
+    Vector {
+        value: 63,
+        encoding: b"\xfe",
+    },
+    Vector {
+        value: -64,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 64,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: -65,
+        encoding: b"\x40\x01",
+    },
+    Vector {
+        value: 8255,
+        encoding: b"\x7f\xfe",
+    },
+    Vector {
+        value: -8256,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 8256,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: -8257,
+        encoding: b"\x20\x00\x01",
+    },
+    Vector {
+        value: 1056831,
+        encoding: b"\x3f\xff\xfe",
+    },
+    Vector {
+        value: -1056832,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 1056832,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: -1056833,
+        encoding: b"\x10\x00\x00\x01",
+    },
+    Vector {
+        value: 135274559,
+        encoding: b"\x1f\xff\xff\xfe",
+    },
+    Vector {
+        value: -135274560,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 135274560,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -135274561,
+        encoding: b"\x08\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 17315143743,
+        encoding: b"\x0f\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -17315143744,
+        encoding: b"\x0f\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 17315143744,
+        encoding: b"\x04\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -17315143745,
+        encoding: b"\x04\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 2216338399295,
+        encoding: b"\x07\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -2216338399296,
+        encoding: b"\x07\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 2216338399296,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -2216338399297,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 283691315109951,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -283691315109952,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 283691315109952,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -283691315109953,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 36312488334073919,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -36312488334073920,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 36312488334073920,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -36312488334073921,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 4647998506761461823,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -4647998506761461824,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 4647998506761461824,
+        encoding: b"\x00\x80\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -4647998506761461825,
+        encoding: b"\x00\x80\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 9259684525188849727,
+        encoding: b"\x00\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -9259684525188849728,
+        encoding: b"\x00\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 9259684525188849728,
+        encoding: b"\x00\x40\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -9259684525188849729,
+        encoding: b"\x00\x40\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 599555494883894501439,
+        encoding: b"\x00\x7f\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -599555494883894501440,
+        encoding: b"\x00\x7f\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 599555494883894501440,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x41\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -599555494883894501441,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x41\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 1189851305242600153151,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -1189851305242600153152,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 1189851305242600153152,
+        encoding: b"\x00\x20\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -1189851305242600153153,
+        encoding: b"\x00\x20\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 76747715031156923572287,
+        encoding: b"\x00\x3f\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -76747715031156923572288,
+        encoding: b"\x00\x3f\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 76747715031156923572288,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x20\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -76747715031156923572289,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x20\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 152305578757071246991423,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -152305578757071246991424,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 152305578757071246991424,
+        encoding: b"\x00\x10\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -152305578757071246991425,
+        encoding: b"\x00\x10\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 9823712135674104644640831,
+        encoding: b"\x00\x1f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -9823712135674104644640832,
+        encoding: b"\x00\x1f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 9823712135674104644640832,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x10\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -9823712135674104644640833,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x10\x40\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 19495118692591138042290239,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -19495118692591138042290240,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 19495118692591138042290240,
+        encoding: b"\x00\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -19495118692591138042290241,
+        encoding: b"\x00\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 1257435157977971412941414463,
+        encoding: b"\x00\x0f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -1257435157977971412941414464,
+        encoding: b"\x00\x0f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 1257435157977971412941414464,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x08\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -1257435157977971412941414465,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x08\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 2495375197263351687840538687,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -2495375197263351687840538688,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 2495375197263351687840538688,
+        encoding: b"\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -2495375197263351687840538689,
+        encoding: b"\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 160951700225792026874928439359,
+        encoding: b"\x00\x07\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -160951700225792026874928439360,
+        encoding: b"\x00\x07\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 160951700225792026874928439360,
+        encoding: b"\x00\x00\x00\x00\x00\x04\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -160951700225792026874928439361,
+        encoding: b"\x00\x00\x00\x00\x00\x04\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 319408025254320702062016340031,
+        encoding: b"\x00\x00\x00\x00\x00\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -319408025254320702062016340032,
+        encoding: b"\x00\x00\x00\x00\x00\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 319408025254320702062016340032,
+        encoding: b"\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -319408025254320702062016340033,
+        encoding: b"\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 20601817628905991126009267626047,
+        encoding: b"\x00\x03\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -20601817628905991126009267626048,
+        encoding: b"\x00\x03\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 20601817628905991126009267626048,
+        encoding: b"\x00\x00\x00\x00\x02\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -20601817628905991126009267626049,
+        encoding: b"\x00\x00\x00\x00\x02\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: 40884227232557661549956518912063,
+        encoding: b"\x00\x00\x00\x00\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7e",
+    },
+    Vector {
+        value: -40884227232557661549956518912064,
+        encoding: b"\x00\x00\x00\x00\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x7f",
+    },
+    Vector {
+        value: 40884227232557661549956518912064,
+        encoding: b"\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: -40884227232557661549956518912065,
+        encoding: b"\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+    },
+    Vector {
+        value: 2637032656499971475815204683522111,
+        encoding: b"\x00\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: -2637032656499971475815204683522112,
+        encoding: b"\x00\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 2637032656499971475815204683522112,
+        encoding: b"\x00\x00\x00\x01\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x80",
+    },
+    Vector {
+        value: -2637032656499971475815204683522113,
+        encoding: b"\x00\x00\x00\x01\x04\x08\x10\x20\x40\x81\x01\x02\x04\x08\x10\x20\x40\x81",
+    },
+    Vector {
+        value: i128::MAX,
+        encoding: b"\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe",
+    },
+    Vector {
+        value: i128::MIN,
+        encoding: b"\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+];
+
+/// `Vu128V2` values at both ends of each length class.
+pub const VU128_V2: &[Vector<u128>] = &[
+    Vector {
+        value: 0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: 127,
+        encoding: b"\xff",
+    },
+    Vector {
+        value: 128,
+        encoding: b"\x40\x00",
+    },
+    Vector {
+        value: 16511,
+        encoding: b"\x7f\xff",
+    },
+    Vector {
+        value: 16512,
+        encoding: b"\x20\x00\x00",
+    },
+    Vector {
+        value: 2113663,
+        encoding: b"\x3f\xff\xff",
+    },
+    Vector {
+        value: 2113664,
+        encoding: b"\x10\x00\x00\x00",
+    },
+    Vector {
+        value: 270549119,
+        encoding: b"\x1f\xff\xff\xff",
+    },
+    Vector {
+        value: 270549120,
+        encoding: b"\x08\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 34630287487,
+        encoding: b"\x0f\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 34630287488,
+        encoding: b"\x04\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 4432676798591,
+        encoding: b"\x07\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 4432676798592,
+        encoding: b"\x02\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 567382630219903,
+        encoding: b"\x03\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 567382630219904,
+        encoding: b"\x01\x80\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 36596179649183871,
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 36596179649183872,
+        encoding: b"\x01\x40\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 4648282198076571775,
+        encoding: b"\x01\x7f\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 4648282198076571776,
+        encoding: b"\x01\x20\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 594944092556782223487,
+        encoding: b"\x01\x3f\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 594944092556782223488,
+        encoding: b"\x01\x10\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 76152807818471105642623,
+        encoding: b"\x01\x1f\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 76152807818471105642624,
+        encoding: b"\x01\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 9747559364735504503292031,
+        encoding: b"\x01\x0f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 9747559364735504503292032,
+        encoding: b"\x01\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 1247687598650115779402416255,
+        encoding: b"\x01\x07\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 1247687598650115779402416256,
+        encoding: b"\x01\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 159704012627178790966490316927,
+        encoding: b"\x01\x03\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 159704012627178790966490316928,
+        encoding: b"\x01\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 20442113616278849214913741602943,
+        encoding: b"\x01\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 20442113616278849214913741602944,
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: 5212738972151106477745410070823039,
+        encoding: b"\x01\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    },
+    Vector {
+        value: 5212738972151106477745410070823040,
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    },
+    Vector {
+        value: u128::MAX,
+        encoding: b"\x00\xff\xfe\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7d\xfb\xf7\xef\xdf\xbf\x7f",
+    },
+];
+
+/// `Vf32` values covering signed zeros, subnormals, infinities and NaN.
+pub const VF32: &[Vector<f32>] = &[
+    Vector {
+        value: 0.0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: -0.0,
+        encoding: b"\x81",
+    },
+    Vector {
+        value: 1.0,
+        encoding: b"\x86",
+    },
+    Vector {
+        value: -1.0,
+        encoding: b"\x87",
+    },
+    Vector {
+        value: 0.5,
+        encoding: b"\x88",
+    },
+    Vector {
+        value: 1.5,
+        encoding: b"\x41\x86",
+    },
+    Vector {
+        value: -2.0,
+        encoding: b"\x8b",
+    },
+    Vector {
+        value: 100.0,
+        encoding: b"\x51\x9e",
+    },
+    Vector {
+        value: 0.1,
+        encoding: b"\x08\xa3\x12\xf1\x94",
+    },
+    Vector {
+        value: f32::MIN_POSITIVE,
+        encoding: b"\x41\x7c",
+    },
+    Vector {
+        value: f32::MAX,
+        encoding: b"\x08\xef\xdf\xbd\x82",
+    },
+    Vector {
+        value: f32::MIN,
+        encoding: b"\x08\xef\xdf\xbd\x83",
+    },
+    Vector {
+        value: f32::from_bits(1),
+        encoding: b"\x08\x6f\xdf\xbf\x80",
+    },
+    Vector {
+        value: f32::INFINITY,
+        encoding: b"\x84",
+    },
+    Vector {
+        value: f32::NEG_INFINITY,
+        encoding: b"\x85",
+    },
+    Vector {
+        value: f32::NAN,
+        encoding: b"\x41\x84",
+    },
+];
+
+/// `Vf64` values covering signed zeros, subnormals, infinities and NaN.
+pub const VF64: &[Vector<f64>] = &[
+    Vector {
+        value: 0.0,
+        encoding: b"\x80",
+    },
+    Vector {
+        value: -0.0,
+        encoding: b"\x81",
+    },
+    Vector {
+        value: 1.0,
+        encoding: b"\x86",
+    },
+    Vector {
+        value: -1.0,
+        encoding: b"\x87",
+    },
+    Vector {
+        value: 0.5,
+        encoding: b"\x88",
+    },
+    Vector {
+        value: 1.5,
+        encoding: b"\x4f\x86",
+    },
+    Vector {
+        value: -2.0,
+        encoding: b"\x8b",
+    },
+    Vector {
+        value: 100.0,
+        encoding: b"\x20\x4f\x9e",
+    },
+    Vector {
+        value: 0.1,
+        encoding: b"\x00\x58\x97\x95\x91\x89\x79\x4f\x94",
+    },
+    Vector {
+        value: f64::MIN_POSITIVE,
+        encoding: b"\x4f\x7c",
+    },
+    Vector {
+        value: f64::MAX,
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xaf\x82",
+    },
+    Vector {
+        value: f64::MIN,
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xaf\x83",
+    },
+    Vector {
+        value: f64::from_bits(1),
+        encoding: b"\x00\x7e\xfd\xfb\xf7\xef\xdf\xbf\x80",
+    },
+    Vector {
+        value: f64::INFINITY,
+        encoding: b"\x84",
+    },
+    Vector {
+        value: f64::NEG_INFINITY,
+        encoding: b"\x85",
+    },
+    Vector {
+        value: f64::NAN,
+        encoding: b"\x4f\x84",
+    },
+];
+
+/// `Vu128` encodings that decode but are not canonical.
+///
+/// The 18-byte raw form can hold any value, including those with shorter encodings.
+pub const VU128_NON_CANONICAL: &[NonCanonicalVector<u128>] = &[
+    NonCanonicalVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        value: 0,
+        canonical: b"\x80",
+    },
+    NonCanonicalVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x7f",
+        value: 127,
+        canonical: b"\xff",
+    },
+    NonCanonicalVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff",
+        value: u64::MAX as u128,
+        canonical: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x7f",
+    },
+];
+
+/// Inputs that fail to decode as `Vu32`, `Vi32` or `Vf32`.
+pub const VU32_INVALID: &[InvalidVector] = &[
+    InvalidVector {
+        encoding: b"",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x40",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x08\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    // The 5-byte form has room for 35 bits, and `u32::MAX` ends at 08efdfbf7f
+    InvalidVector {
+        encoding: b"\x08\xef\xdf\xbf\x80",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x0f\xff\xff\xff\xff",
+        error: DecodeError::Overflow,
+    },
+    // A first byte below 0x08 starts a `Vu64` of 6 bytes or more, which cannot hold a
+    // `u32`
+    InvalidVector {
+        encoding: b"\x07\xff\xff\xff\xff",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x00\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x01\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x02\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x03\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x04\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x05\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x06\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x07\x00\x00\x00\x00",
+        error: DecodeError::Overflow,
+    },
+];
+
+/// Inputs that fail to decode as `Vu64`, `Vi64` or `Vf64`.
+pub const VU64_INVALID: &[InvalidVector] = &[
+    InvalidVector {
+        encoding: b"",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x01\xff\xff\xff\xff\xff\xff",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    // The 9-byte form has room for values past `u64::MAX`, which ends at
+    // 00fefdfbf7efdfbf7f
+    InvalidVector {
+        encoding: b"\x00\xfe\xfd\xfb\xf7\xef\xdf\xbf\x80",
+        error: DecodeError::Overflow,
+    },
+    InvalidVector {
+        encoding: b"\x00\xff\xff\xff\xff\xff\xff\xff\xff",
+        error: DecodeError::Overflow,
+    },
+];
+
+/// Inputs that fail to decode as `Vu128` or `Vi128`.
+///
+/// Every `Vu128` length class fits in a `u128`, so only truncated input fails.
+pub const VU128_INVALID: &[InvalidVector] = &[
+    InvalidVector {
+        encoding: b"",
+        error: DecodeError::UnexpectedEof,
+    },
+    // A zero first byte needs the second to give the length
+    InvalidVector {
+        encoding: b"\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x00\x80\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+];
+
+/// Inputs that fail to decode as `Vu128V2`.
+pub const VU128_V2_INVALID: &[InvalidVector] = &[
+    InvalidVector {
+        encoding: b"",
+        error: DecodeError::UnexpectedEof,
+    },
+    // A first byte of 1 needs the second to give the length
+    InvalidVector {
+        encoding: b"\x01",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    InvalidVector {
+        encoding: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        error: DecodeError::UnexpectedEof,
+    },
+    // The 17-byte form has room for values past `u128::MAX`
+    InvalidVector {
+        encoding: b"\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+        error: DecodeError::Overflow,
+    },
+];
+
+/// A table value as JSON object members.
+trait JsonValue {
+    fn write_json(&self, out: &mut String);
+}
+
+macro_rules! impl_json_int {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonValue for $ty {
+                fn write_json(&self, out: &mut String) {
+                    // Strings keep 64- and 128-bit values exact in every JSON parser
+                    let _ = write!(out, r#""value": "{self}""#);
+                }
+            }
+        )*
+    };
+}
+
+impl_json_int!(u32, i32, u64, i64, u128, i128);
+
+impl JsonValue for f32 {
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(
+            out,
+            r#""value": "{self}", "bits": "{:08x}""#,
+            self.to_bits()
+        );
+    }
+}
+
+impl JsonValue for f64 {
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(
+            out,
+            r#""value": "{self}", "bits": "{:016x}""#,
+            self.to_bits()
+        );
+    }
+}
+
+fn write_hex(out: &mut String, bytes: &[u8]) {
+    out.push('"');
+    for b in bytes {
+        let _ = write!(out, "{b:02x}");
+    }
+    out.push('"');
+}
+
+/// Stable names for errors in the JSON export.
+fn error_name(error: DecodeError) -> &'static str {
+    match error {
+        DecodeError::UnexpectedEof => "unexpected_eof",
+        DecodeError::LengthLimitExceeded { .. } => "length_limit_exceeded",
+        DecodeError::InvalidUtf8(_) => "invalid_utf8",
+        DecodeError::Overflow => "overflow",
+        DecodeError::InvalidScale(_) => "invalid_scale",
+        DecodeError::InvalidNanos(_) => "invalid_nanos",
+        DecodeError::NonCanonical => "non_canonical",
+        DecodeError::InvalidDiscriminant(_) => "invalid_discriminant",
+        DecodeError::InvalidBool(_) => "invalid_bool",
+    }
+}
+
+/// Write `"name": [...]` with one object per entry.
+fn write_list<T>(
+    out: &mut String,
+    name: &str,
+    entries: &[T],
+    mut write: impl FnMut(&mut String, &T),
+) {
+    let _ = write!(out, "    \"{name}\": [");
+    for (i, entry) in entries.iter().enumerate() {
+        out.push_str(if i == 0 { "\n      {" } else { ",\n      {" });
+        write(out, entry);
+        out.push('}');
+    }
+    out.push_str(if entries.is_empty() { "]" } else { "\n    ]" });
+}
+
+fn write_type<T: JsonValue>(
+    out: &mut String,
+    name: &str,
+    vectors: &[Vector<T>],
+    non_canonical: &[NonCanonicalVector<T>],
+    invalid: &[InvalidVector],
+) {
+    let _ = writeln!(out, "  \"{name}\": {{");
+    write_list(out, "vectors", vectors, |out, v| {
+        v.value.write_json(out);
+        out.push_str(r#", "encoding": "#);
+        write_hex(out, v.encoding);
+    });
+    out.push_str(",\n");
+    write_list(out, "non_canonical", non_canonical, |out, v| {
+        out.push_str(r#""encoding": "#);
+        write_hex(out, v.encoding);
+        out.push_str(", ");
+        v.value.write_json(out);
+        out.push_str(r#", "canonical": "#);
+        write_hex(out, v.canonical);
+    });
+    out.push_str(",\n");
+    write_list(out, "invalid", invalid, |out, v| {
+        out.push_str(r#""encoding": "#);
+        write_hex(out, v.encoding);
+        let _ = write!(out, r#", "error": "{}""#, error_name(v.error));
+    });
+    out.push_str("\n  }");
+}
+
+/// Export every table as a JSON object keyed by type name.
+///
+/// Each type has `vectors`, `non_canonical` and `invalid` arrays. Encodings are hex
+/// strings, values are decimal strings so that 128-bit values survive any parser, and
+/// float values carry their IEEE 754 `bits` in hex as well. Non-canonical entries are
+/// listed under `vu128` only, while each type repeats the invalid inputs of its layout.
+pub fn to_json() -> String {
+    let mut out = String::from("{\n");
+    write_type(&mut out, "vu32", VU32, &[], VU32_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vi32", VI32, &[], VU32_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vu64", VU64, &[], VU64_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vi64", VI64, &[], VU64_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vu128", VU128, VU128_NON_CANONICAL, VU128_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vi128", VI128, &[], VU128_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vu128_v2", VU128_V2, &[], VU128_V2_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vf32", VF32, &[], VU32_INVALID);
+    out.push_str(",\n");
+    write_type(&mut out, "vf64", VF64, &[], VU64_INVALID);
+    out.push_str("\n}\n");
+    out
+}
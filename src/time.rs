@@ -0,0 +1,162 @@
+//! Durations and timestamps.
+//!
+//! A `Duration` is encoded as its whole seconds as a `Vu64`, followed by its subsecond
+//! nanoseconds as a `Vu32`. A `SystemTime` uses the same layout with the seconds as a
+//! `Vi64` relative to the Unix epoch, rounded down, so times before the epoch have
+//! negative seconds and the nanoseconds always count forwards. Decoding rejects
+//! nanoseconds of one billion or more.
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use crate::error::DecodeError;
+use crate::vu32::{VU32_BUF_SIZE, decode_len_vu32, decode_vu32, encode_vu32, split_vu32};
+use crate::vu64::{VU64_BUF_SIZE, decode_len_vu64, decode_vu64, encode_vu64, split_vu64};
+
+pub(crate) const VDURATION_BUF_SIZE: usize = VU64_BUF_SIZE + VU32_BUF_SIZE;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Reject subsecond nanoseconds that would carry into the seconds.
+#[inline(always)]
+pub(crate) const fn check_nanos(nanos: u32) -> Result<u32, DecodeError> {
+    if nanos >= NANOS_PER_SEC {
+        return Err(DecodeError::InvalidNanos(nanos));
+    }
+    Ok(nanos)
+}
+
+/// Split a timestamp into seconds since the epoch, rounded down, and nanoseconds.
+///
+/// Returns `None` if the seconds do not fit in an `i64`.
+#[cfg(feature = "std")]
+pub(crate) fn split_system_time(t: std::time::SystemTime) -> Option<(i64, u32)> {
+    match t.duration_since(std::time::UNIX_EPOCH) {
+        Ok(d) => Some((i64::try_from(d.as_secs()).ok()?, d.subsec_nanos())),
+        Err(e) => {
+            let d = e.duration();
+            let secs = 0i64.checked_sub_unsigned(d.as_secs())?;
+            match d.subsec_nanos() {
+                0 => Some((secs, 0)),
+                nanos => Some((secs.checked_sub(1)?, NANOS_PER_SEC - nanos)),
+            }
+        }
+    }
+}
+
+/// Rebuild a timestamp from seconds since the epoch and checked nanoseconds.
+#[cfg(feature = "std")]
+pub(crate) fn join_system_time(
+    secs: i64,
+    nanos: u32,
+) -> Result<std::time::SystemTime, DecodeError> {
+    let whole = Duration::from_secs(secs.unsigned_abs());
+    let t = if secs >= 0 {
+        std::time::UNIX_EPOCH.checked_add(whole)
+    } else {
+        std::time::UNIX_EPOCH.checked_sub(whole)
+    };
+    t.and_then(|t| t.checked_add(Duration::from_nanos(nanos as u64)))
+        .ok_or(DecodeError::Overflow)
+}
+
+/// Encode a `Duration` to VLQ.
+#[inline(always)]
+#[must_use]
+pub const fn encode_duration(d: Duration) -> VDuration {
+    let mut out_buf = [0u8; VDURATION_BUF_SIZE];
+    let secs = encode_vu64(d.as_secs());
+    let nanos = encode_vu32(d.subsec_nanos());
+    let secs_len = secs.len() as usize;
+    let mut i = 0;
+    while i < secs_len {
+        out_buf[i] = secs.0[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < VU32_BUF_SIZE {
+        out_buf[secs_len + i] = nanos.0[i];
+        i += 1;
+    }
+    VDuration(out_buf)
+}
+
+/// Decode a `Duration` from the front of `buf`.
+///
+/// Returns the duration and the number of bytes consumed. Fails if the nanoseconds are
+/// one billion or more.
+pub fn decode_duration(buf: &[u8]) -> Result<(Duration, usize), DecodeError> {
+    let (secs, offset) = split_vu64(buf)?;
+    let (nanos, len) = split_vu32(&buf[offset..])?;
+    let nanos = check_nanos(decode_vu32(nanos))?;
+    Ok((Duration::new(decode_vu64(secs), nanos), offset + len))
+}
+
+/// A `Duration` in value-length quantity encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct VDuration([u8; VDURATION_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl VDuration {
+    /// Maximum length of an encoded `VDuration` in bytes.
+    pub const MAX_LEN: usize = VDURATION_BUF_SIZE;
+
+    /// Construct a new VLQ instance from the given `Duration`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: Duration) -> VDuration {
+        encode_duration(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        let secs_len = decode_len_vu64(self.0[0]);
+        secs_len + decode_len_vu32(self.0[secs_len as usize])
+    }
+
+    /// Retrieve the stored `Duration`.
+    #[inline(always)]
+    pub fn get(&self) -> Duration {
+        match decode_duration(&self.0) {
+            Ok((d, _)) => d,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 14] {
+        self.0
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+}
+
+impl From<Duration> for VDuration {
+    fn from(d: Duration) -> Self {
+        encode_duration(d)
+    }
+}
+
+impl From<VDuration> for Duration {
+    fn from(d: VDuration) -> Self {
+        d.get()
+    }
+}
+
+impl Debug for VDuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "VDuration(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
@@ -0,0 +1,135 @@
+//! 32-bit floating-point VLQ encoding.
+//!
+//! The IEEE-754 bits are rearranged the same way as for `Vf64` before being encoded as
+//! a `Vu32`, with an 8-bit exponent code:
+//!
+//! ```text
+//! <mantissa, bit-reversed> <exponent code, 8 bits> <sign>
+//! ```
+
+use core::fmt::{Debug, Display};
+
+use crate::vu32::{Vu32, decode_vu32, encode_vu32};
+
+#[inline(always)]
+pub(crate) const fn float_encode_f32(n: f32) -> u32 {
+    let bits = n.to_bits();
+    let sign = bits >> 31;
+    let exponent = (bits >> 23) & 0xFF;
+    let mantissa = bits & ((1 << 23) - 1);
+    // Sign-extend the unbiased exponent from 8 bits before zigzagging it
+    let unbiased = ((exponent.wrapping_sub(127) << 24) as i32) >> 24;
+    let zigzag = ((unbiased << 1) ^ (unbiased >> 31)) as u32;
+    let code = (zigzag + 3) & 0xFF;
+    mantissa.reverse_bits() | (code << 1) | sign
+}
+
+#[inline(always)]
+pub(crate) const fn float_decode_f32(n: u32) -> f32 {
+    let sign = n & 1;
+    let code = (n >> 1) & 0xFF;
+    let mantissa = (n & !0x1FF).reverse_bits();
+    let zigzag = code.wrapping_sub(3) & 0xFF;
+    let unbiased = ((zigzag >> 1) as i32) ^ -((zigzag & 1) as i32);
+    let exponent = (unbiased as u32).wrapping_add(127) & 0xFF;
+    f32::from_bits((sign << 31) | (exponent << 23) | mantissa)
+}
+
+/// Encode an f32 to VLQ.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vf32(n: f32) -> Vf32 {
+    Vf32(encode_vu32(float_encode_f32(n)))
+}
+
+/// Decode a Vf32 back to a native f32.
+#[inline(always)]
+pub const fn decode_vf32(n: Vf32) -> f32 {
+    float_decode_f32(decode_vu32(n.0))
+}
+
+/// A 32-bit floating-point number in value-length quantity encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vf32(Vu32);
+
+#[allow(clippy::len_without_is_empty)]
+impl Vf32 {
+    /// Maximum length of an encoded `Vf32` in bytes.
+    pub const MAX_LEN: usize = Vu32::MAX_LEN;
+
+    /// Construct a new VLQ instance from the given `f32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: f32) -> Vf32 {
+        encode_vf32(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        self.0.len()
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: f32) -> u8 {
+        Vu32::encoded_len(float_encode_f32(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    ///
+    /// As with [`Vu32::len_from_first_byte`], `0x00` to `0x07` give 5 but never start a
+    /// valid encoding.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        Vu32::len_from_first_byte(first)
+    }
+
+    /// Retrieve the stored number as `f32`.
+    #[inline(always)]
+    pub const fn get(&self) -> f32 {
+        decode_vf32(*self)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 5] {
+        self.0.bytes()
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<f32> for Vf32 {
+    fn from(n: f32) -> Self {
+        encode_vf32(n)
+    }
+}
+
+impl From<Vf32> for f32 {
+    fn from(n: Vf32) -> Self {
+        decode_vf32(n)
+    }
+}
+
+impl Display for Vf32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Vf32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Vf32(0b")?;
+        for x in self.0.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0.0[len]))
+    }
+}
@@ -0,0 +1,137 @@
+//! 64-bit floating-point VLQ encoding.
+//!
+//! The IEEE-754 bits are rearranged before being encoded as a `Vu64`:
+//!
+//! ```text
+//! <mantissa, bit-reversed> <exponent code, 11 bits> <sign>
+//! ```
+//!
+//! The exponent code is the unbiased exponent zigzagged and offset by 3, so that zero
+//! and subnormals take code 0 and exponents near 1.0 take small codes. Reversing the
+//! mantissa keeps its significant bits low, so values with short mantissas such as
+//! `1.0`, `0.5` or `-2.0` take one byte and `100.0` takes three. Every step is a
+//! bijection on the raw bits, so NaN payloads and `-0.0` round-trip exactly.
+
+use core::fmt::{Debug, Display};
+
+use crate::vu64::{Vu64, decode_vu64, encode_vu64};
+
+#[inline(always)]
+pub(crate) const fn float_encode_f64(n: f64) -> u64 {
+    let bits = n.to_bits();
+    let sign = bits >> 63;
+    let exponent = (bits >> 52) & 0x7FF;
+    let mantissa = bits & ((1 << 52) - 1);
+    // Sign-extend the unbiased exponent from 11 bits before zigzagging it
+    let unbiased = ((exponent.wrapping_sub(1023) << 53) as i64) >> 53;
+    let zigzag = ((unbiased << 1) ^ (unbiased >> 63)) as u64;
+    let code = (zigzag + 3) & 0x7FF;
+    mantissa.reverse_bits() | (code << 1) | sign
+}
+
+#[inline(always)]
+pub(crate) const fn float_decode_f64(n: u64) -> f64 {
+    let sign = n & 1;
+    let code = (n >> 1) & 0x7FF;
+    let mantissa = (n & !0xFFF).reverse_bits();
+    let zigzag = code.wrapping_sub(3) & 0x7FF;
+    let unbiased = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+    let exponent = (unbiased as u64).wrapping_add(1023) & 0x7FF;
+    f64::from_bits((sign << 63) | (exponent << 52) | mantissa)
+}
+
+/// Encode an f64 to VLQ.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vf64(n: f64) -> Vf64 {
+    Vf64(encode_vu64(float_encode_f64(n)))
+}
+
+/// Decode a Vf64 back to a native f64.
+#[inline(always)]
+pub const fn decode_vf64(n: Vf64) -> f64 {
+    float_decode_f64(decode_vu64(n.0))
+}
+
+/// A 64-bit floating-point number in value-length quantity encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vf64(Vu64);
+
+#[allow(clippy::len_without_is_empty)]
+impl Vf64 {
+    /// Maximum length of an encoded `Vf64` in bytes.
+    pub const MAX_LEN: usize = Vu64::MAX_LEN;
+
+    /// Construct a new VLQ instance from the given `f64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: f64) -> Vf64 {
+        encode_vf64(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        self.0.len()
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: f64) -> u8 {
+        Vu64::encoded_len(float_encode_f64(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        Vu64::len_from_first_byte(first)
+    }
+
+    /// Retrieve the stored number as `f64`.
+    #[inline(always)]
+    pub const fn get(&self) -> f64 {
+        decode_vf64(*self)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 9] {
+        self.0.bytes()
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<f64> for Vf64 {
+    fn from(n: f64) -> Self {
+        encode_vf64(n)
+    }
+}
+
+impl From<Vf64> for f64 {
+    fn from(n: Vf64) -> Self {
+        decode_vf64(n)
+    }
+}
+
+impl Display for Vf64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Vf64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Vf64(0b")?;
+        for x in self.0.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0.0[len]))
+    }
+}
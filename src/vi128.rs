@@ -2,6 +2,11 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
+use crate::vi32::Vi32;
+use crate::vi64::Vi64;
+use crate::vu32::Vu32;
+use crate::vu64::Vu64;
 use crate::vu128::{Vu128, decode_vu128, encode_vu128};
 
 #[inline(always)]
@@ -34,6 +39,9 @@ pub struct Vi128(Vu128);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vi128 {
+    /// Maximum length of an encoded `Vi128` in bytes.
+    pub const MAX_LEN: usize = Vu128::MAX_LEN;
+
     /// Construct a new VLQ instance from the given `i128`.
     #[inline(always)]
     #[must_use]
@@ -47,6 +55,20 @@ impl Vi128 {
         self.0.len()
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: i128) -> u8 {
+        Vu128::encoded_len(zigzag_encode_i128(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first` and `second`.
+    ///
+    /// See [`Vu128::len_from_prefix`].
+    #[inline(always)]
+    pub const fn len_from_prefix(first: u8, second: u8) -> u8 {
+        Vu128::len_from_prefix(first, second)
+    }
+
     /// Retrieve the stored number as `i128`.
     #[inline(always)]
     pub const fn get(&self) -> i128 {
@@ -64,6 +86,31 @@ impl Vi128 {
     pub fn as_slice(&self) -> &[u8] {
         self.0.as_slice()
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails as [`Vu128::from_bytes`] does.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vi128, usize), DecodeError> {
+        Vu128::from_bytes(buf).map(|(n, len)| (Vi128(n), len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails as [`Vu128::from_array`] does.
+    pub fn from_array(bytes: [u8; 18]) -> Result<Vi128, DecodeError> {
+        Vu128::from_array(bytes).map(Vi128)
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array).
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 18]) -> Vi128 {
+        // SAFETY: forwarded from the caller
+        Vi128(unsafe { Vu128::from_array_unchecked(bytes) })
+    }
 }
 
 impl From<i128> for Vi128 {
@@ -78,6 +125,45 @@ impl From<Vi128> for i128 {
     }
 }
 
+/// Zigzag maps a value to the same unsigned number at every width, so this converts as
+/// `From<Vu32> for Vu128` does.
+impl From<Vi32> for Vi128 {
+    fn from(n: Vi32) -> Self {
+        Vi128(Vu128::from(n.0))
+    }
+}
+
+/// Converts as `From<Vu64> for Vu128` does.
+impl From<Vi64> for Vi128 {
+    fn from(n: Vi64) -> Self {
+        Vi128(Vu128::from(n.0))
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in an `i32`.
+impl TryFrom<Vi128> for Vi32 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vi128) -> Result<Self, Self::Error> {
+        Vu32::try_from(n.0).map(Vi32)
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in an `i64`.
+impl TryFrom<Vi128> for Vi64 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vi128) -> Result<Self, Self::Error> {
+        Vu64::try_from(n.0).map(Vi64)
+    }
+}
+
+impl_int_traits!(
+    Vi128(i128),
+    from: [i8, i16, i32, i64, u8, u16, u32, u64],
+    try_from: [isize, u128, usize]
+);
+
 impl Display for Vi128 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
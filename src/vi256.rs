@@ -0,0 +1,166 @@
+//! Signed 256-bit VLQ encoding (zigzag).
+//!
+//! Values are passed as 32-byte big-endian two's complement arrays.
+
+use core::fmt::{Debug, Display};
+
+use crate::error::DecodeError;
+use crate::vu256::{Vu256, decode_vu256, encode_vu256, fmt_u256};
+
+#[inline(always)]
+pub(crate) const fn zigzag_encode_i256(n: [u8; 32]) -> [u8; 32] {
+    // (n << 1) ^ (n >> 255), with the arithmetic shift filling every bit with the sign
+    let negative = n[0] & 0x80 != 0;
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        let carry = if i < 31 { n[i + 1] >> 7 } else { 0 };
+        out[i] = (n[i] << 1) | carry;
+        if negative {
+            out[i] = !out[i];
+        }
+        i += 1;
+    }
+    out
+}
+
+#[inline(always)]
+pub(crate) const fn zigzag_decode_i256(n: [u8; 32]) -> [u8; 32] {
+    // (n >> 1) ^ -(n & 1)
+    let negative = n[31] & 1 != 0;
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        let carry = if i > 0 { n[i - 1] << 7 } else { 0 };
+        out[i] = (n[i] >> 1) | carry;
+        if negative {
+            out[i] = !out[i];
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Encode a big-endian two's complement i256 using zigzag encoding to VLQ.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vi256(n: [u8; 32]) -> Vi256 {
+    Vi256(encode_vu256(zigzag_encode_i256(n)))
+}
+
+/// Decode a Vi256 back to a big-endian two's complement i256.
+#[inline(always)]
+pub const fn decode_vi256(n: Vi256) -> [u8; 32] {
+    zigzag_decode_i256(decode_vu256(n.0))
+}
+
+/// A signed 256-bit integer in value-length quantity encoding using zigzag.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vi256(Vu256);
+
+#[allow(clippy::len_without_is_empty)]
+impl Vi256 {
+    /// Maximum length of an encoded `Vi256` in bytes.
+    pub const MAX_LEN: usize = Vu256::MAX_LEN;
+
+    /// Construct a new VLQ instance from the given big-endian two's complement i256.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: [u8; 32]) -> Vi256 {
+        encode_vi256(value)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        self.0.len()
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: [u8; 32]) -> u8 {
+        Vu256::encoded_len(zigzag_encode_i256(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first`, `second` and `third`.
+    ///
+    /// See [`Vu256::len_from_prefix`].
+    #[inline(always)]
+    pub const fn len_from_prefix(first: u8, second: u8, third: u8) -> Result<u8, DecodeError> {
+        Vu256::len_from_prefix(first, second, third)
+    }
+
+    /// Retrieve the stored number as a big-endian two's complement i256.
+    #[inline(always)]
+    pub const fn get(&self) -> [u8; 32] {
+        decode_vi256(*self)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 35] {
+        self.0.bytes()
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails as [`Vu256::from_bytes`] does.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vi256, usize), DecodeError> {
+        Vu256::from_bytes(buf).map(|(n, len)| (Vi256(n), len))
+    }
+}
+
+impl From<[u8; 32]> for Vi256 {
+    fn from(n: [u8; 32]) -> Self {
+        encode_vi256(n)
+    }
+}
+
+impl From<i128> for Vi256 {
+    fn from(n: i128) -> Self {
+        let mut buf = if n < 0 { [0xff; 32] } else { [0; 32] };
+        buf[16..].copy_from_slice(&n.to_be_bytes());
+        encode_vi256(buf)
+    }
+}
+
+impl From<Vi256> for [u8; 32] {
+    fn from(n: Vi256) -> Self {
+        decode_vi256(n)
+    }
+}
+
+impl Display for Vi256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let mut n = self.get();
+        let negative = n[0] & 0x80 != 0;
+        if negative {
+            // Two's complement negation; the minimum value's magnitude is its own bits
+            let mut carry = true;
+            for b in n.iter_mut().rev() {
+                let (sum, overflow) = (!*b).overflowing_add(carry as u8);
+                *b = sum;
+                carry = overflow;
+            }
+        }
+        fmt_u256(n, !negative, f)
+    }
+}
+
+impl Debug for Vi256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Vi256(0b")?;
+        for x in self.0.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0.0[len]))
+    }
+}
@@ -2,6 +2,7 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
 use crate::vu32::{Vu32, decode_vu32, encode_vu32};
 
 #[inline(always)]
@@ -30,10 +31,13 @@ pub const fn decode_vi32(n: Vi32) -> i32 {
 /// A signed 32-bit integer in value-length quantity encoding using zigzag.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
-pub struct Vi32(Vu32);
+pub struct Vi32(pub(crate) Vu32);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vi32 {
+    /// Maximum length of an encoded `Vi32` in bytes.
+    pub const MAX_LEN: usize = Vu32::MAX_LEN;
+
     /// Construct a new VLQ instance from the given `i32`.
     #[inline(always)]
     #[must_use]
@@ -47,6 +51,21 @@ impl Vi32 {
         self.0.len()
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: i32) -> u8 {
+        Vu32::encoded_len(zigzag_encode_i32(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    ///
+    /// As with [`Vu32::len_from_first_byte`], `0x00` to `0x07` give 5 but never start a
+    /// valid encoding.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        Vu32::len_from_first_byte(first)
+    }
+
     /// Retrieve the stored number as `i32`.
     #[inline(always)]
     pub const fn get(&self) -> i32 {
@@ -64,6 +83,31 @@ impl Vi32 {
     pub fn as_slice(&self) -> &[u8] {
         self.0.as_slice()
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails as [`Vu32::from_bytes`] does.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vi32, usize), DecodeError> {
+        Vu32::from_bytes(buf).map(|(n, len)| (Vi32(n), len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails as [`Vu32::from_array`] does.
+    pub fn from_array(bytes: [u8; 5]) -> Result<Vi32, DecodeError> {
+        Vu32::from_array(bytes).map(Vi32)
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array).
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 5]) -> Vi32 {
+        // SAFETY: forwarded from the caller
+        Vi32(unsafe { Vu32::from_array_unchecked(bytes) })
+    }
 }
 
 impl From<i32> for Vi32 {
@@ -78,6 +122,12 @@ impl From<Vi32> for i32 {
     }
 }
 
+impl_int_traits!(
+    Vi32(i32),
+    from: [i8, i16, u8, u16],
+    try_from: [i64, i128, isize, u32, u64, u128, usize]
+);
+
 impl Display for Vi32 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
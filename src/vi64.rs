@@ -2,6 +2,9 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
+use crate::vi32::Vi32;
+use crate::vu32::Vu32;
 use crate::vu64::{Vu64, decode_vu64, encode_vu64};
 
 #[inline(always)]
@@ -30,10 +33,13 @@ pub const fn decode_vi64(n: Vi64) -> i64 {
 /// A signed 64-bit integer in value-length quantity encoding using zigzag.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
-pub struct Vi64(Vu64);
+pub struct Vi64(pub(crate) Vu64);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vi64 {
+    /// Maximum length of an encoded `Vi64` in bytes.
+    pub const MAX_LEN: usize = Vu64::MAX_LEN;
+
     /// Construct a new VLQ instance from the given `i64`.
     #[inline(always)]
     #[must_use]
@@ -47,6 +53,18 @@ impl Vi64 {
         self.0.len()
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: i64) -> u8 {
+        Vu64::encoded_len(zigzag_encode_i64(n))
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        Vu64::len_from_first_byte(first)
+    }
+
     /// Retrieve the stored number as `i64`.
     #[inline(always)]
     pub const fn get(&self) -> i64 {
@@ -64,6 +82,31 @@ impl Vi64 {
     pub fn as_slice(&self) -> &[u8] {
         self.0.as_slice()
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails as [`Vu64::from_bytes`] does.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vi64, usize), DecodeError> {
+        Vu64::from_bytes(buf).map(|(n, len)| (Vi64(n), len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails as [`Vu64::from_array`] does.
+    pub fn from_array(bytes: [u8; 9]) -> Result<Vi64, DecodeError> {
+        Vu64::from_array(bytes).map(Vi64)
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array).
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 9]) -> Vi64 {
+        // SAFETY: forwarded from the caller
+        Vi64(unsafe { Vu64::from_array_unchecked(bytes) })
+    }
 }
 
 impl From<i64> for Vi64 {
@@ -78,6 +121,29 @@ impl From<Vi64> for i64 {
     }
 }
 
+/// Zigzag maps a value to the same unsigned number at every width, so this converts as
+/// `From<Vu32> for Vu64` does.
+impl From<Vi32> for Vi64 {
+    fn from(n: Vi32) -> Self {
+        Vi64(Vu64::from(n.0))
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in an `i32`.
+impl TryFrom<Vi64> for Vi32 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vi64) -> Result<Self, Self::Error> {
+        Vu32::try_from(n.0).map(Vi32)
+    }
+}
+
+impl_int_traits!(
+    Vi64(i64),
+    from: [i8, i16, i32, u8, u16, u32],
+    try_from: [i128, isize, u64, u128, usize]
+);
+
 impl Display for Vi64 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
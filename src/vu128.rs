@@ -2,6 +2,10 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
+use crate::vu32::Vu32;
+use crate::vu64::{VU64_BUF_SIZE, Vu64};
+
 pub(crate) const VU128_BUF_SIZE: usize = 18;
 
 /// Determine encoded length for u128.
@@ -46,27 +50,34 @@ const fn encode_len_vu128(n: u128) -> u8 {
         return 9;
     }
 
-    // Extended encoding (10-17 bytes)
+    // Below the 9-byte range the extended offsets are out of reach, so only the raw
+    // 18-byte form can represent these values. See `Vu128V2` for a tighter layout.
+    if n < nine_byte_min {
+        return 18;
+    }
+
+    // Extended encoding (10-16 bytes). Each class carries one bit less than the gap to
+    // the next offset, so the upper half of each range only fits the raw form.
     if n < offset!(11) {
-        return 10;
+        return if n - offset!(10) < 1 << 70 { 10 } else { 18 };
     }
     if n < offset!(12) {
-        return 11;
+        return if n - offset!(11) < 1 << 77 { 11 } else { 18 };
     }
     if n < offset!(13) {
-        return 12;
+        return if n - offset!(12) < 1 << 84 { 12 } else { 18 };
     }
     if n < offset!(14) {
-        return 13;
+        return if n - offset!(13) < 1 << 91 { 13 } else { 18 };
     }
     if n < offset!(15) {
-        return 14;
+        return if n - offset!(14) < 1 << 98 { 14 } else { 18 };
     }
     if n < offset!(16) {
-        return 15;
+        return if n - offset!(15) < 1 << 105 { 15 } else { 18 };
     }
     if n < offset!(17) {
-        return 16;
+        return if n - offset!(16) < 1 << 112 { 16 } else { 18 };
     }
     // For values >= offset!(17), use 18-byte raw encoding
     // This simplifies disambiguation (no need to distinguish 17 vs 18)
@@ -583,6 +594,22 @@ pub const fn decode_vu128(n: Vu128) -> u128 {
     }
 }
 
+/// Copy a complete encoding off the front of `buf`, along with its length in bytes.
+#[inline(always)]
+pub(crate) fn split_vu128(buf: &[u8]) -> Option<(Vu128, usize)> {
+    let first = *buf.first()?;
+    // Second byte only matters for the extended forms
+    let second = if first == 0 { *buf.get(1)? } else { 0 };
+    let len = decode_len_vu128(first, second) as usize;
+    let mut out_buf = [0u8; VU128_BUF_SIZE];
+    match buf.first_chunk::<VU128_BUF_SIZE>() {
+        // Fixed-size copy; bytes past `len` are ignored by the decoder
+        Some(chunk) => out_buf = *chunk,
+        None => out_buf[..len].copy_from_slice(buf.get(..len)?),
+    }
+    Some((Vu128(out_buf), len))
+}
+
 /// An unsigned 128-bit integer in value-length quantity encoding.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
@@ -590,6 +617,9 @@ pub struct Vu128(pub(crate) [u8; VU128_BUF_SIZE]);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vu128 {
+    /// Maximum length of an encoded `Vu128` in bytes.
+    pub const MAX_LEN: usize = VU128_BUF_SIZE;
+
     /// Construct a new VLQ instance from the given `u128`.
     #[inline(always)]
     #[must_use]
@@ -603,6 +633,22 @@ impl Vu128 {
         decode_len_vu128(self.0[0], self.0[1])
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u128) -> u8 {
+        encode_len_vu128(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first` and `second`.
+    ///
+    /// Unlike the narrower types, the first byte alone is not enough: when it is `0x00`
+    /// the second byte selects between the 9-byte and extended forms. `second` is
+    /// ignored otherwise, so any value may be passed if it has not been read yet.
+    #[inline(always)]
+    pub const fn len_from_prefix(first: u8, second: u8) -> u8 {
+        decode_len_vu128(first, second)
+    }
+
     /// Retrieve the stored number as `u128`.
     #[inline(always)]
     pub const fn get(&self) -> u128 {
@@ -620,6 +666,43 @@ impl Vu128 {
     pub fn as_slice(&self) -> &[u8] {
         &self.0[..(self.len() as usize)]
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails with [`DecodeError::UnexpectedEof`] if `buf` ends early, and
+    /// [`DecodeError::NonCanonical`] if the bytes are not what `new` produces for their
+    /// value. Bytes after the encoding are ignored.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vu128, usize), DecodeError> {
+        let first = *buf.first().ok_or(DecodeError::UnexpectedEof)?;
+        let len = decode_len_vu128(first, buf.get(1).copied().unwrap_or(0)) as usize;
+        let mut out_buf = [0u8; VU128_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        Vu128::from_array(out_buf).map(|n| (n, len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails with [`DecodeError::NonCanonical`] unless `bytes` is exactly what `new`
+    /// produces for its value, including the zeros past the end of the encoding.
+    pub fn from_array(bytes: [u8; 18]) -> Result<Vu128, DecodeError> {
+        let n = Vu128(bytes);
+        if encode_vu128(decode_vu128(n)).0 == bytes {
+            Ok(n)
+        } else {
+            Err(DecodeError::NonCanonical)
+        }
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array). Equality, hashing
+    /// and ordering rely on every `Vu128` holding the canonical encoding of its value.
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 18]) -> Vu128 {
+        Vu128(bytes)
+    }
 }
 
 impl From<u128> for Vu128 {
@@ -634,6 +717,60 @@ impl From<Vu128> for u128 {
     }
 }
 
+/// Every `Vu32` encoding is also the `Vu128` encoding of the same value, so the bytes are
+/// copied as they are.
+impl From<Vu32> for Vu128 {
+    fn from(n: Vu32) -> Self {
+        Vu128::from(Vu64::from(n))
+    }
+}
+
+/// `Vu64` encodings of up to 8 bytes are copied as they are, as is the 9-byte form when
+/// its second byte is at least `0x80`. Smaller 9-byte values are re-encoded in the raw
+/// 18-byte form, since `Vu128` reads a lower second byte as an extended length.
+impl From<Vu64> for Vu128 {
+    fn from(n: Vu64) -> Self {
+        if n.len() as usize == VU64_BUF_SIZE && n.0[1] < 0x80 {
+            return Vu128::new(n.get() as u128);
+        }
+        let mut out_buf = [0u8; VU128_BUF_SIZE];
+        out_buf[..VU64_BUF_SIZE].copy_from_slice(&n.0);
+        Vu128(out_buf)
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in a `u32`.
+impl TryFrom<Vu128> for Vu32 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vu128) -> Result<Self, Self::Error> {
+        Vu32::try_from(Vu64::try_from(n)?)
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in a `u64`. Encodings of
+/// up to 8 bytes are copied as they are.
+impl TryFrom<Vu128> for Vu64 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vu128) -> Result<Self, Self::Error> {
+        if n.len() as usize >= VU64_BUF_SIZE {
+            return u64::try_from(n.get())
+                .map(Vu64::new)
+                .map_err(|_| DecodeError::Overflow);
+        }
+        let mut out_buf = [0u8; VU64_BUF_SIZE];
+        out_buf.copy_from_slice(&n.0[..VU64_BUF_SIZE]);
+        Ok(Vu64(out_buf))
+    }
+}
+
+impl_int_traits!(
+    Vu128(u128),
+    from: [u8, u16, u32, u64],
+    try_from: [usize, i8, i16, i32, i64, i128, isize]
+);
+
 impl Display for Vu128 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
@@ -0,0 +1,252 @@
+//! Unsigned 128-bit VLQ encoding, version 2.
+//!
+//! The v2 layout keeps the 1–7 byte classes of `Vu64`, so values below `offset!(8)`
+//! encode identically in `Vu64`, `Vu128` and `Vu128V2`. Longer values spend the
+//! `0x01` first byte on a second unary prefix, and `0x00` marks the 17-byte form:
+//!
+//! ```text
+//! 1xxx_xxxx                               1 byte,     7 bits
+//! ...
+//! 0000_001x ...                           7 bytes,   49 bits
+//! 0000_0001 1xxx_xxxx ...                 8 bytes,   55 bits
+//! 0000_0001 01xx_xxxx ...                 9 bytes,   62 bits
+//! ...
+//! 0000_0001 0000_0001 ...                15 bytes,  104 bits
+//! 0000_0001 0000_0000 ...                16 bytes,  112 bits
+//! 0000_0000 ...                          17 bytes,  128 bits
+//! ```
+//!
+//! Every length class is offset by the capacity of the classes below it, so each value
+//! has exactly one encoding. The only invalid encodings are 17-byte forms whose payload
+//! would overflow a `u128` once offset.
+
+use core::fmt::{Debug, Display};
+
+use crate::error::DecodeError;
+use crate::vu128::{Vu128, decode_vu128, split_vu128};
+
+pub(crate) const VU128_V2_BUF_SIZE: usize = 17;
+
+/// Payload bits carried by each length class, indexed by length in bytes.
+const BITS: [u32; 18] = [
+    0, 7, 14, 21, 28, 35, 42, 49, 55, 62, 69, 76, 83, 90, 97, 104, 112, 128,
+];
+
+/// Smallest value carried by each length class, indexed by length in bytes.
+const OFFSETS: [u128; 18] = {
+    let mut out = [0u128; 18];
+    let mut len = 2;
+    while len < 18 {
+        out[len] = out[len - 1] + (1u128 << BITS[len - 1]);
+        len += 1;
+    }
+    out
+};
+
+/// Decode length from the first two bytes for v2 u128.
+///
+/// The second byte is only inspected when the first is `0x01`.
+#[inline(always)]
+pub(crate) const fn decode_len_vu128_v2(first: u8, second: u8) -> u8 {
+    match first {
+        0 => 17,
+        1 if second == 0 => 16,
+        1 => 8 + second.leading_zeros() as u8,
+        _ => first.leading_zeros() as u8 + 1,
+    }
+}
+
+#[inline(always)]
+const fn encode_len_vu128_v2(n: u128) -> u8 {
+    let mut len = 1;
+    while len < 17 && n >= OFFSETS[len + 1] {
+        len += 1;
+    }
+    len as u8
+}
+
+/// Encode a u128 in the v2 value-length quantity encoding.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vu128_v2(n: u128) -> Vu128V2 {
+    let len = encode_len_vu128_v2(n) as usize;
+    let payload = n - OFFSETS[len];
+    let mut out_buf = [0u8; VU128_V2_BUF_SIZE];
+
+    // Lengths up to 15 carry their unary length marker just above the payload
+    let tagged = if len <= 15 {
+        payload | (1u128 << BITS[len])
+    } else {
+        payload
+    };
+    let body = if len <= 7 { len } else { len - 1 };
+    if len >= 8 {
+        out_buf[0] = if len == 17 { 0x00 } else { 0x01 };
+    }
+
+    let buf = tagged.to_be_bytes();
+    let mut i = 0;
+    while i < body {
+        out_buf[len - body + i] = buf[16 - body + i];
+        i += 1;
+    }
+
+    Vu128V2(out_buf)
+}
+
+/// Decode an encoding, returning `None` if a 17-byte payload overflows once offset.
+#[inline(always)]
+const fn checked_decode_vu128_v2(n: &Vu128V2) -> Option<u128> {
+    let len = n.len() as usize;
+    let mut tagged = 0u128;
+    let mut i = if len <= 7 { 0 } else { 1 };
+    while i < len {
+        tagged = (tagged << 8) | n.0[i] as u128;
+        i += 1;
+    }
+    let payload = if len <= 15 {
+        tagged & ((1u128 << BITS[len]) - 1)
+    } else {
+        tagged
+    };
+    payload.checked_add(OFFSETS[len])
+}
+
+/// Decode a Vu128V2 back to a native u128.
+#[inline(always)]
+pub const fn decode_vu128_v2(n: Vu128V2) -> u128 {
+    match checked_decode_vu128_v2(&n) {
+        Some(v) => v,
+        None => unreachable!(),
+    }
+}
+
+/// Convert a v1 `Vu128` encoding at the front of `buf` to the v2 layout.
+///
+/// Returns the v2 encoding and the number of v1 bytes consumed. Signed values convert
+/// the same way, as both versions apply zigzag before encoding.
+pub fn vu128_v1_to_v2(buf: &[u8]) -> Result<(Vu128V2, usize), DecodeError> {
+    let (v1, len) = split_vu128(buf).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((Vu128V2::from(v1), len))
+}
+
+/// An unsigned 128-bit integer in the v2 value-length quantity encoding.
+///
+/// Unlike [`Vu128`], every value has exactly one encoding and the longest is 17 bytes.
+/// The two layouts agree for values below 2^49.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vu128V2(pub(crate) [u8; VU128_V2_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl Vu128V2 {
+    /// Maximum length of an encoded `Vu128V2` in bytes.
+    pub const MAX_LEN: usize = VU128_V2_BUF_SIZE;
+
+    /// Construct a new VLQ instance from the given `u128`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: u128) -> Vu128V2 {
+        encode_vu128_v2(value)
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u128) -> u8 {
+        encode_len_vu128_v2(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first` and `second`.
+    ///
+    /// `second` is only inspected when `first` is `0x01`.
+    #[inline(always)]
+    pub const fn len_from_prefix(first: u8, second: u8) -> u8 {
+        decode_len_vu128_v2(first, second)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        decode_len_vu128_v2(self.0[0], self.0[1])
+    }
+
+    /// Retrieve the stored number as `u128`.
+    #[inline(always)]
+    pub const fn get(&self) -> u128 {
+        decode_vu128_v2(*self)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 17] {
+        self.0
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails with [`DecodeError::UnexpectedEof`] if `buf` ends early, and
+    /// [`DecodeError::Overflow`] if a 17-byte payload overflows a `u128` once offset.
+    /// Bytes after the encoding are ignored.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vu128V2, usize), DecodeError> {
+        let first = *buf.first().ok_or(DecodeError::UnexpectedEof)?;
+        let second = match first {
+            1 => *buf.get(1).ok_or(DecodeError::UnexpectedEof)?,
+            _ => 0,
+        };
+        let len = decode_len_vu128_v2(first, second) as usize;
+        let mut out_buf = [0u8; VU128_V2_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        let n = Vu128V2(out_buf);
+        n.validate()?;
+        Ok((n, len))
+    }
+
+    #[inline(always)]
+    pub(crate) const fn validate(&self) -> Result<(), DecodeError> {
+        match checked_decode_vu128_v2(self) {
+            Some(_) => Ok(()),
+            None => Err(DecodeError::Overflow),
+        }
+    }
+}
+
+impl From<u128> for Vu128V2 {
+    fn from(n: u128) -> Self {
+        encode_vu128_v2(n)
+    }
+}
+
+impl From<Vu128V2> for u128 {
+    fn from(n: Vu128V2) -> Self {
+        decode_vu128_v2(n)
+    }
+}
+
+impl From<Vu128> for Vu128V2 {
+    fn from(n: Vu128) -> Self {
+        encode_vu128_v2(decode_vu128(n))
+    }
+}
+
+impl Display for Vu128V2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Debug for Vu128V2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Vu128V2(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
@@ -0,0 +1,315 @@
+//! Unsigned 256-bit VLQ encoding.
+//!
+//! Values that `Vu128` encodes in 16 bytes or fewer use exactly the same bytes, which
+//! includes every 1–8 byte `Vu64` encoding. Larger values use the `VuBig` long form
+//! with a single-byte length:
+//!
+//! ```text
+//! 0000_0000 0000_0000 1xxx_xxxx <magnitude>
+//! ```
+//!
+//! so every `Vu256` is also a valid `VuBig`. Values are passed as 32-byte big-endian
+//! arrays.
+
+use core::fmt::{Debug, Display};
+
+use crate::error::DecodeError;
+use crate::vu128::{Vu128, decode_len_vu128, decode_vu128, encode_vu128};
+
+pub(crate) const VU256_BUF_SIZE: usize = 35;
+
+/// Decode length of the long form from its length byte, rejecting magnitudes that
+/// cannot fit in 256 bits.
+#[inline(always)]
+pub(crate) const fn decode_long_len_vu256(third: u8) -> Result<u8, DecodeError> {
+    if third & 0x80 == 0 || unprefix!(1, third) > 32 {
+        return Err(DecodeError::Overflow);
+    }
+    Ok(3 + unprefix!(1, third))
+}
+
+/// Decode length from the first three bytes for u256, without checking the long form.
+///
+/// The second byte is only inspected when the first is `0x00`, and the third only
+/// when both are.
+#[inline(always)]
+pub(crate) const fn decode_len_vu256(first: u8, second: u8, third: u8) -> u8 {
+    if first == 0 && second == 0 {
+        3 + unprefix!(1, third)
+    } else {
+        decode_len_vu128(first, second)
+    }
+}
+
+/// Decode length from the first three bytes for u256, rejecting long forms that claim
+/// more than 32 bytes of magnitude.
+#[inline(always)]
+pub(crate) const fn checked_len_vu256(first: u8, second: u8, third: u8) -> Result<u8, DecodeError> {
+    if first == 0 && second == 0 {
+        decode_long_len_vu256(third)
+    } else {
+        Ok(decode_len_vu128(first, second))
+    }
+}
+
+/// Number of leading zero bytes in a big-endian value.
+#[inline(always)]
+const fn leading_zero_bytes(n: &[u8; 32]) -> usize {
+    let mut i = 0;
+    while i < 32 && n[i] == 0 {
+        i += 1;
+    }
+    i
+}
+
+/// The `Vu128` short form of a value, if it has one.
+#[inline(always)]
+const fn short_form(n: &[u8; 32]) -> Option<Vu128> {
+    if leading_zero_bytes(n) < 16 {
+        return None;
+    }
+    let mut low = [0u8; 16];
+    let mut i = 0;
+    while i < 16 {
+        low[i] = n[16 + i];
+        i += 1;
+    }
+    let vlq = encode_vu128(u128::from_be_bytes(low));
+    if vlq.len() <= 16 { Some(vlq) } else { None }
+}
+
+#[inline(always)]
+const fn encode_len_vu256(n: [u8; 32]) -> u8 {
+    match short_form(&n) {
+        Some(vlq) => vlq.len(),
+        None => 3 + (32 - leading_zero_bytes(&n)) as u8,
+    }
+}
+
+/// Encode a big-endian u256 in value-length quantity encoding.
+#[inline(always)]
+#[must_use]
+pub const fn encode_vu256(n: [u8; 32]) -> Vu256 {
+    let mut out_buf = [0u8; VU256_BUF_SIZE];
+
+    if let Some(vlq) = short_form(&n) {
+        let buf = vlq.bytes();
+        copy_from_slice_offset!(source = buf, dest = out_buf, offset = 16);
+        return Vu256(out_buf);
+    }
+
+    let start = leading_zero_bytes(&n);
+    let len = 32 - start;
+    out_buf[2] = prefix!(1, len as u8);
+    let mut i = 0;
+    while i < len {
+        out_buf[3 + i] = n[start + i];
+        i += 1;
+    }
+
+    Vu256(out_buf)
+}
+
+/// Decode a Vu256 back to a big-endian u256.
+#[inline(always)]
+pub const fn decode_vu256(n: Vu256) -> [u8; 32] {
+    let b = n.0;
+    let mut out = [0u8; 32];
+
+    if b[0] == 0 && b[1] == 0 {
+        let len = unprefix!(1, b[2]) as usize;
+        let mut i = 0;
+        while i < len {
+            out[32 - len + i] = b[3 + i];
+            i += 1;
+        }
+        return out;
+    }
+
+    let mut short = [0u8; 18];
+    copy_from_slice_offset!(source = b, dest = short, offset = 16);
+    let low = decode_vu128(Vu128(short)).to_be_bytes();
+    let mut i = 0;
+    while i < 16 {
+        out[16 + i] = low[i];
+        i += 1;
+    }
+    out
+}
+
+/// Write the decimal digits of a big-endian magnitude.
+pub(crate) fn fmt_u256(
+    mut n: [u8; 32],
+    nonnegative: bool,
+    f: &mut core::fmt::Formatter,
+) -> core::fmt::Result {
+    // 2^256 has 78 decimal digits
+    let mut digits = [0u8; 78];
+    let mut i = digits.len();
+    loop {
+        let mut rem = 0u16;
+        for b in n.iter_mut() {
+            let cur = (rem << 8) | *b as u16;
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        i -= 1;
+        digits[i] = b'0' + rem as u8;
+        if n.iter().all(|&b| b == 0) {
+            break;
+        }
+    }
+    // Only ASCII digits were written
+    let s = core::str::from_utf8(&digits[i..]).map_err(|_| core::fmt::Error)?;
+    f.pad_integral(nonnegative, "", s)
+}
+
+/// An unsigned 256-bit integer in value-length quantity encoding.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vu256(pub(crate) [u8; VU256_BUF_SIZE]);
+
+#[allow(clippy::len_without_is_empty)]
+impl Vu256 {
+    /// Maximum length of an encoded `Vu256` in bytes.
+    pub const MAX_LEN: usize = VU256_BUF_SIZE;
+
+    /// Construct a new VLQ instance from the given big-endian u256.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(value: [u8; 32]) -> Vu256 {
+        encode_vu256(value)
+    }
+
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: [u8; 32]) -> u8 {
+        encode_len_vu256(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`, `second` and `third`.
+    ///
+    /// `second` is only inspected when `first` is `0x00`, and `third` only when both
+    /// are. Fails with [`DecodeError::Overflow`] if `third` starts a long form of more
+    /// than 32 bytes, so the result never exceeds [`MAX_LEN`](Self::MAX_LEN).
+    #[inline(always)]
+    pub const fn len_from_prefix(first: u8, second: u8, third: u8) -> Result<u8, DecodeError> {
+        checked_len_vu256(first, second, third)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> u8 {
+        decode_len_vu256(self.0[0], self.0[1], self.0[2])
+    }
+
+    /// Retrieve the stored number as a big-endian u256.
+    #[inline(always)]
+    pub const fn get(&self) -> [u8; 32] {
+        decode_vu256(*self)
+    }
+
+    /// Get the raw byte representation of the VLQ instance.
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 35] {
+        self.0
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..(self.len() as usize)]
+    }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails with [`DecodeError::UnexpectedEof`] if `buf` ends early,
+    /// [`DecodeError::Overflow`] if a long form holds more than 32 bytes, and
+    /// [`DecodeError::NonCanonical`] if the bytes are not what `new` produces for their
+    /// value. Bytes after the encoding are ignored.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vu256, usize), DecodeError> {
+        let first = *buf.first().ok_or(DecodeError::UnexpectedEof)?;
+        let second = match first {
+            0 => *buf.get(1).ok_or(DecodeError::UnexpectedEof)?,
+            _ => 0,
+        };
+        let third = match (first, second) {
+            (0, 0) => *buf.get(2).ok_or(DecodeError::UnexpectedEof)?,
+            _ => 0,
+        };
+        let len = checked_len_vu256(first, second, third)? as usize;
+        let mut out_buf = [0u8; VU256_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        let n = Vu256(out_buf);
+        if encode_vu256(decode_vu256(n)).0 == out_buf {
+            Ok((n, len))
+        } else {
+            Err(DecodeError::NonCanonical)
+        }
+    }
+}
+
+impl From<[u8; 32]> for Vu256 {
+    fn from(n: [u8; 32]) -> Self {
+        encode_vu256(n)
+    }
+}
+
+impl From<u128> for Vu256 {
+    fn from(n: u128) -> Self {
+        let mut buf = [0u8; 32];
+        buf[16..].copy_from_slice(&n.to_be_bytes());
+        encode_vu256(buf)
+    }
+}
+
+impl From<Vu256> for [u8; 32] {
+    fn from(n: Vu256) -> Self {
+        decode_vu256(n)
+    }
+}
+
+impl Display for Vu256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_u256(self.get(), true, f)
+    }
+}
+
+impl Debug for Vu256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.len() as usize - 1;
+        write!(f, "Vu256(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+impl From<primitive_types::U256> for Vu256 {
+    fn from(n: primitive_types::U256) -> Self {
+        encode_vu256(n.to_big_endian())
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+impl From<Vu256> for primitive_types::U256 {
+    fn from(n: Vu256) -> Self {
+        primitive_types::U256::from_big_endian(&decode_vu256(n))
+    }
+}
+
+#[cfg(feature = "ruint")]
+impl From<ruint::aliases::U256> for Vu256 {
+    fn from(n: ruint::aliases::U256) -> Self {
+        encode_vu256(n.to_be_bytes())
+    }
+}
+
+#[cfg(feature = "ruint")]
+impl From<Vu256> for ruint::aliases::U256 {
+    fn from(n: Vu256) -> Self {
+        ruint::aliases::U256::from_be_bytes(decode_vu256(n))
+    }
+}
@@ -2,15 +2,21 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
+
 pub(crate) const VU32_BUF_SIZE: usize = 5;
 
 /// Decode length from first byte for u32 (max 5 bytes).
+///
+/// First bytes below `0x08` are capped at 5, and rejected by `Vu32::validate`.
 #[inline(always)]
 pub(crate) const fn decode_len_vu32(n: u8) -> u8 {
     let len = n.leading_zeros() as u8 + 1;
     if len > 5 { 5 } else { len }
 }
 
+// The offsets are cast alike whether or not the macro already typed them
+#[allow(clippy::unnecessary_cast)]
 #[inline(always)]
 const fn encode_len_vu32(n: u32) -> u8 {
     match n {
@@ -64,6 +70,7 @@ pub const fn encode_vu32(n: u32) -> Vu32 {
 }
 
 /// Decode a Vu32 back to a native u32.
+#[allow(clippy::unnecessary_cast)]
 #[inline(always)]
 pub const fn decode_vu32(n: Vu32) -> u32 {
     let len = n.len();
@@ -75,13 +82,28 @@ pub const fn decode_vu32(n: Vu32) -> u32 {
         3 => u32::from_le_bytes([n[2], n[1], unprefix!(3, n[0]), 0]) + offset!(3) as u32,
         4 => u32::from_le_bytes([n[3], n[2], n[1], unprefix!(4, n[0])]) + offset!(4) as u32,
         _ => {
-            // 5 bytes
+            // 5 bytes; `validate` keeps payloads past `u32::MAX` out of a `Vu32`
             let val = u64::from_le_bytes([n[4], n[3], n[2], n[1], unprefix!(5, n[0]), 0, 0, 0]);
             (val + offset!(5)) as u32
         }
     }
 }
 
+/// Copy a complete encoding off the front of `buf`, along with its length in bytes.
+#[inline(always)]
+pub(crate) fn split_vu32(buf: &[u8]) -> Result<(Vu32, usize), DecodeError> {
+    let len = decode_len_vu32(*buf.first().ok_or(DecodeError::UnexpectedEof)?) as usize;
+    let mut out_buf = [0u8; VU32_BUF_SIZE];
+    match buf.first_chunk::<VU32_BUF_SIZE>() {
+        // Fixed-size copy; bytes past `len` are ignored by the decoder
+        Some(chunk) => out_buf = *chunk,
+        None => out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?),
+    }
+    let vlq = Vu32(out_buf);
+    vlq.validate()?;
+    Ok((vlq, len))
+}
+
 /// An unsigned 32-bit integer in value-length quantity encoding.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
@@ -89,6 +111,9 @@ pub struct Vu32(pub(crate) [u8; VU32_BUF_SIZE]);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vu32 {
+    /// Maximum length of an encoded `Vu32` in bytes.
+    pub const MAX_LEN: usize = VU32_BUF_SIZE;
+
     /// Construct a new VLQ instance from the given `u32`.
     #[inline(always)]
     #[must_use]
@@ -102,6 +127,22 @@ impl Vu32 {
         decode_len_vu32(self.0[0])
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u32) -> u8 {
+        encode_len_vu32(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    ///
+    /// A first byte of `0x00` to `0x07` gives 5, but never starts a valid `Vu32`: it is
+    /// the start of a `Vu64` of 6 bytes or more, and decoding fails with
+    /// [`DecodeError::Overflow`].
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        decode_len_vu32(first)
+    }
+
     /// Retrieve the stored number as `u32`.
     #[inline(always)]
     pub const fn get(&self) -> u32 {
@@ -119,6 +160,64 @@ impl Vu32 {
     pub fn as_slice(&self) -> &[u8] {
         &self.0[..(self.len() as usize)]
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails with [`DecodeError::UnexpectedEof`] if `buf` ends early,
+    /// [`DecodeError::Overflow`] if the bytes encode a value past `u32::MAX`, and
+    /// [`DecodeError::NonCanonical`] if they are not what `new` produces for their value.
+    /// Bytes after the encoding are ignored.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vu32, usize), DecodeError> {
+        let len = decode_len_vu32(*buf.first().ok_or(DecodeError::UnexpectedEof)?) as usize;
+        let mut out_buf = [0u8; VU32_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        Vu32::from_array(out_buf).map(|n| (n, len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails as [`from_bytes`](Self::from_bytes) does, and with
+    /// [`DecodeError::NonCanonical`] unless `bytes` is exactly what `new` produces for its
+    /// value, including the zeros past the end of the encoding.
+    pub fn from_array(bytes: [u8; 5]) -> Result<Vu32, DecodeError> {
+        let n = Vu32(bytes);
+        n.validate()?;
+        if encode_vu32(decode_vu32(n)).0 == bytes {
+            Ok(n)
+        } else {
+            Err(DecodeError::NonCanonical)
+        }
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array). Equality, hashing
+    /// and ordering rely on every `Vu32` holding the canonical encoding of its value.
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 5]) -> Vu32 {
+        Vu32(bytes)
+    }
+
+    /// Reject encodings of values past `u32::MAX`.
+    ///
+    /// A first byte below `0x08` starts a `Vu64` of 6 bytes or more, whose value cannot
+    /// fit, and the 5-byte form has room for 35 bits.
+    #[inline(always)]
+    pub(crate) const fn validate(&self) -> Result<(), DecodeError> {
+        let n = self.0;
+        if n[0] < prefix!(5) {
+            return Err(DecodeError::Overflow);
+        }
+        if decode_len_vu32(n[0]) == 5 {
+            let val = u64::from_le_bytes([n[4], n[3], n[2], n[1], unprefix!(5, n[0]), 0, 0, 0]);
+            if val + offset!(5) > u32::MAX as u64 {
+                return Err(DecodeError::Overflow);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl From<u32> for Vu32 {
@@ -133,6 +232,12 @@ impl From<Vu32> for u32 {
     }
 }
 
+impl_int_traits!(
+    Vu32(u32),
+    from: [u8, u16],
+    try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize]
+);
+
 impl Display for Vu32 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
@@ -2,6 +2,9 @@
 
 use core::fmt::{Debug, Display};
 
+use crate::error::DecodeError;
+use crate::vu32::{VU32_BUF_SIZE, Vu32};
+
 pub(crate) const VU64_BUF_SIZE: usize = 9;
 
 /// Decoding bit depth by prefix in bits:
@@ -107,6 +110,8 @@ pub const fn encode_vu64(n: u64) -> Vu64 {
 }
 
 /// Decode a given VLQ instance back into a native u64.
+// Each arm casts its offset to `u64`, even those `offset!` already made one
+#[allow(clippy::unnecessary_cast)]
 #[inline(always)]
 pub const fn decode_vu64(n: Vu64) -> u64 {
     let len = n.len();
@@ -139,11 +144,28 @@ pub const fn decode_vu64(n: Vu64) -> u64 {
                 + offset!(8) as u64
         }
         _ => {
-            u64::from_le_bytes([n[8], n[7], n[6], n[5], n[4], n[3], n[2], n[1]]) + offset!(9) as u64
+            // `validate` keeps payloads past `u64::MAX` out of a `Vu64`
+            u64::from_le_bytes([n[8], n[7], n[6], n[5], n[4], n[3], n[2], n[1]])
+                .wrapping_add(offset!(9) as u64)
         }
     }
 }
 
+/// Copy a complete encoding off the front of `buf`, along with its length in bytes.
+#[inline(always)]
+pub(crate) fn split_vu64(buf: &[u8]) -> Result<(Vu64, usize), DecodeError> {
+    let len = decode_len_vu64(*buf.first().ok_or(DecodeError::UnexpectedEof)?) as usize;
+    let mut out_buf = [0u8; VU64_BUF_SIZE];
+    match buf.first_chunk::<VU64_BUF_SIZE>() {
+        // Fixed-size copy; bytes past `len` are ignored by the decoder
+        Some(chunk) => out_buf = *chunk,
+        None => out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?),
+    }
+    let vlq = Vu64(out_buf);
+    vlq.validate()?;
+    Ok((vlq, len))
+}
+
 /// An unsigned 64-bit integer in value-length quantity encoding.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
@@ -151,6 +173,9 @@ pub struct Vu64(pub(crate) [u8; VU64_BUF_SIZE]);
 
 #[allow(clippy::len_without_is_empty)]
 impl Vu64 {
+    /// Maximum length of an encoded `Vu64` in bytes.
+    pub const MAX_LEN: usize = VU64_BUF_SIZE;
+
     /// Construct a new VLQ instance from the given `u64`.
     #[inline(always)]
     #[must_use]
@@ -164,6 +189,18 @@ impl Vu64 {
         decode_len_vu64(self.0[0])
     }
 
+    /// Number of bytes `n` takes up when encoded, without encoding it.
+    #[inline(always)]
+    pub const fn encoded_len(n: u64) -> u8 {
+        encode_len_vu64(n)
+    }
+
+    /// Length in bytes of the encoding that starts with `first`.
+    #[inline(always)]
+    pub const fn len_from_first_byte(first: u8) -> u8 {
+        decode_len_vu64(first)
+    }
+
     /// Retrieve the stored number as `u64`.
     #[inline(always)]
     pub const fn get(&self) -> u64 {
@@ -181,6 +218,60 @@ impl Vu64 {
     pub fn as_slice(&self) -> &[u8] {
         &self.0[..(self.len() as usize)]
     }
+
+    /// Read the encoding at the front of `buf`, along with its length in bytes.
+    ///
+    /// Fails with [`DecodeError::UnexpectedEof`] if `buf` ends early,
+    /// [`DecodeError::Overflow`] if the bytes encode a value past `u64::MAX`, and
+    /// [`DecodeError::NonCanonical`] if they are not what `new` produces for their value.
+    /// Bytes after the encoding are ignored.
+    pub fn from_bytes(buf: &[u8]) -> Result<(Vu64, usize), DecodeError> {
+        let len = decode_len_vu64(*buf.first().ok_or(DecodeError::UnexpectedEof)?) as usize;
+        let mut out_buf = [0u8; VU64_BUF_SIZE];
+        out_buf[..len].copy_from_slice(buf.get(..len).ok_or(DecodeError::UnexpectedEof)?);
+        Vu64::from_array(out_buf).map(|n| (n, len))
+    }
+
+    /// Wrap an encoding held in an array, laid out as [`bytes`](Self::bytes) returns it.
+    ///
+    /// Fails as [`from_bytes`](Self::from_bytes) does, and with
+    /// [`DecodeError::NonCanonical`] unless `bytes` is exactly what `new` produces for its
+    /// value, including the zeros past the end of the encoding.
+    pub fn from_array(bytes: [u8; 9]) -> Result<Vu64, DecodeError> {
+        let n = Vu64(bytes);
+        n.validate()?;
+        if encode_vu64(decode_vu64(n)).0 == bytes {
+            Ok(n)
+        } else {
+            Err(DecodeError::NonCanonical)
+        }
+    }
+
+    /// Wrap an encoding held in an array without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be accepted by [`from_array`](Self::from_array). Equality, hashing
+    /// and ordering rely on every `Vu64` holding the canonical encoding of its value.
+    #[inline(always)]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 9]) -> Vu64 {
+        Vu64(bytes)
+    }
+
+    /// Reject a 9-byte payload that goes past `u64::MAX` once offset.
+    #[allow(clippy::unnecessary_cast)]
+    #[inline(always)]
+    pub(crate) const fn validate(&self) -> Result<(), DecodeError> {
+        let n = self.0;
+        if n[0] != prefix!(9) {
+            return Ok(());
+        }
+        let payload = u64::from_be_bytes([n[1], n[2], n[3], n[4], n[5], n[6], n[7], n[8]]);
+        match payload.checked_add(offset!(9) as u64) {
+            Some(_) => Ok(()),
+            None => Err(DecodeError::Overflow),
+        }
+    }
 }
 
 impl From<u64> for Vu64 {
@@ -195,6 +286,37 @@ impl From<Vu64> for u64 {
     }
 }
 
+/// Every `Vu32` encoding is also the `Vu64` encoding of the same value, so the bytes are
+/// copied as they are.
+impl From<Vu32> for Vu64 {
+    fn from(n: Vu32) -> Self {
+        let mut out_buf = [0u8; VU64_BUF_SIZE];
+        out_buf[..VU32_BUF_SIZE].copy_from_slice(&n.0);
+        Vu64(out_buf)
+    }
+}
+
+/// Fails with [`DecodeError::Overflow`] if the value does not fit in a `u32`. Encodings of
+/// up to 5 bytes are copied as they are.
+impl TryFrom<Vu64> for Vu32 {
+    type Error = DecodeError;
+
+    fn try_from(n: Vu64) -> Result<Self, Self::Error> {
+        if n.len() as usize > VU32_BUF_SIZE || u32::try_from(n.get()).is_err() {
+            return Err(DecodeError::Overflow);
+        }
+        let mut out_buf = [0u8; VU32_BUF_SIZE];
+        out_buf.copy_from_slice(&n.0[..VU32_BUF_SIZE]);
+        Ok(Vu32(out_buf))
+    }
+}
+
+impl_int_traits!(
+    Vu64(u64),
+    from: [u8, u16, u32],
+    try_from: [u128, usize, i8, i16, i32, i64, i128, isize]
+);
+
 impl Display for Vu64 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         Display::fmt(&self.get(), f)
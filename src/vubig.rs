@@ -0,0 +1,181 @@
+//! Arbitrary-precision unsigned VLQ encoding.
+//!
+//! Values that `Vu128` encodes in 16 bytes or fewer use exactly the same bytes. Every
+//! other value takes the long form, which replaces the raw 18-byte `Vu128` form with a
+//! `Vu64` length followed by the big-endian magnitude:
+//!
+//! ```text
+//! 0000_0000 0000_0000 <Vu64 length> <magnitude>
+//! ```
+
+use core::fmt::Debug;
+
+use crate::error::DecodeError;
+use crate::vu64::{decode_vu64, encode_vu64, split_vu64};
+use crate::vu128::{Vu128, decode_vu128, encode_vu128, split_vu128};
+
+/// Longest `Vu128` form shared with `VuBig`.
+pub(crate) const SHORT_MAX_LEN: u8 = 16;
+
+/// Strip leading zero bytes from a big-endian magnitude.
+#[inline(always)]
+pub(crate) fn trim_magnitude(magnitude: &[u8]) -> &[u8] {
+    let start = magnitude
+        .iter()
+        .position(|&b| b != 0)
+        .unwrap_or(magnitude.len());
+    &magnitude[start..]
+}
+
+/// The `Vu128` short form of a magnitude, if it has one.
+#[inline(always)]
+fn short_form(magnitude: &[u8]) -> Option<Vu128> {
+    if magnitude.len() > 16 {
+        return None;
+    }
+    let mut buf = [0u8; 16];
+    buf[16 - magnitude.len()..].copy_from_slice(magnitude);
+    let vlq = encode_vu128(u128::from_be_bytes(buf));
+    (vlq.len() <= SHORT_MAX_LEN).then_some(vlq)
+}
+
+/// Magnitude bytes of a decoded short form, without leading zeros.
+#[inline(always)]
+pub(crate) fn short_magnitude(vlq: Vu128) -> Vec<u8> {
+    trim_magnitude(&decode_vu128(vlq).to_be_bytes()).to_vec()
+}
+
+/// Encode a big-endian magnitude of any size.
+///
+/// Leading zero bytes are ignored, so an empty slice encodes zero.
+#[must_use]
+pub fn encode_big(magnitude: &[u8]) -> VuBig {
+    let magnitude = trim_magnitude(magnitude);
+    if let Some(vlq) = short_form(magnitude) {
+        return VuBig(vlq.as_slice().to_vec());
+    }
+
+    let prefix = encode_vu64(magnitude.len() as u64);
+    let mut out = Vec::with_capacity(2 + prefix.len() as usize + magnitude.len());
+    out.extend_from_slice(&[0x00, 0x00]);
+    out.extend_from_slice(prefix.as_slice());
+    out.extend_from_slice(magnitude);
+    VuBig(out)
+}
+
+/// Decode a `VuBig` from the front of `buf`.
+///
+/// Returns the big-endian magnitude without leading zeros, so zero decodes to an empty
+/// vector, and the number of bytes consumed.
+pub fn decode_big(buf: &[u8]) -> Result<(Vec<u8>, usize), DecodeError> {
+    if buf.starts_with(&[0x00, 0x00]) {
+        let (prefix, offset) = split_vu64(&buf[2..])?;
+        let len = decode_vu64(prefix);
+        let start = 2 + offset;
+        let magnitude = usize::try_from(len)
+            .ok()
+            .and_then(|len| buf[start..].get(..len))
+            .ok_or(DecodeError::UnexpectedEof)?;
+        return Ok((trim_magnitude(magnitude).to_vec(), start + magnitude.len()));
+    }
+
+    let (vlq, len) = split_vu128(buf).ok_or(DecodeError::UnexpectedEof)?;
+    Ok((short_magnitude(vlq), len))
+}
+
+/// An unsigned integer of any size in value-length quantity encoding.
+#[derive(Clone)]
+pub struct VuBig(Vec<u8>);
+
+#[allow(clippy::len_without_is_empty)]
+impl VuBig {
+    /// Construct a new VLQ instance from a big-endian magnitude.
+    #[inline(always)]
+    #[must_use]
+    pub fn new(magnitude: &[u8]) -> VuBig {
+        encode_big(magnitude)
+    }
+
+    /// Length of the internal representation in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Retrieve the stored number as a big-endian magnitude without leading zeros.
+    pub fn magnitude(&self) -> Vec<u8> {
+        match decode_big(&self.0) {
+            Ok((magnitude, _)) => magnitude,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Get the serialized representation of the VLQ as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<u128> for VuBig {
+    fn from(n: u128) -> Self {
+        encode_big(&n.to_be_bytes())
+    }
+}
+
+impl Debug for VuBig {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let len = self.0.len() - 1;
+        write!(f, "VuBig(0b")?;
+        for x in self.0.iter().take(len) {
+            f.write_fmt(core::format_args!("{:08b}_", x))?;
+        }
+        f.write_fmt(core::format_args!("{:08b})", self.0[len]))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+mod bigint {
+    use num_bigint::{BigInt, BigUint, Sign};
+
+    use super::{VuBig, encode_big};
+
+    impl From<&BigUint> for VuBig {
+        fn from(n: &BigUint) -> Self {
+            encode_big(&n.to_bytes_be())
+        }
+    }
+
+    impl From<&VuBig> for BigUint {
+        fn from(n: &VuBig) -> Self {
+            BigUint::from_bytes_be(&n.magnitude())
+        }
+    }
+
+    impl VuBig {
+        /// Construct a new VLQ instance from a signed `BigInt` using zigzag encoding.
+        ///
+        /// Values that fit in an `i128` encode the same as `Vi128` where the `Vu128`
+        /// forms are shared.
+        #[must_use]
+        pub fn from_bigint(n: &BigInt) -> VuBig {
+            let zigzag: BigUint = match n.sign() {
+                Sign::Minus => (n.magnitude() << 1u32) - 1u32,
+                _ => n.magnitude() << 1u32,
+            };
+            VuBig::from(&zigzag)
+        }
+
+        /// Retrieve the stored number as a signed `BigInt`, undoing zigzag encoding.
+        pub fn to_bigint(&self) -> BigInt {
+            let zigzag = BigUint::from(self);
+            let negative = zigzag.bit(0);
+            let magnitude = zigzag >> 1u32;
+            if negative {
+                -BigInt::from(magnitude) - 1
+            } else {
+                BigInt::from(magnitude)
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Round-trip length-prefixed byte strings and check the length and UTF-8 checks.
+
+use fastvlq::{DecodeError, ReadVlqExt, Vu64, WriteVlqExt, decode_vbytes, decode_vstr};
+
+fn decode_error(err: &std::io::Error) -> DecodeError {
+    *err.get_ref()
+        .and_then(|inner| inner.downcast_ref::<DecodeError>())
+        .unwrap()
+}
+
+/// A length prefix followed by `payload`, which need not be `len` bytes long.
+fn prefixed(len: u64, payload: &[u8]) -> Vec<u8> {
+    [Vu64::new(len).as_slice(), payload].concat()
+}
+
+#[test]
+fn round_trips() {
+    for payload in [&b""[..], b"a", &[0xff; 127], &[0x00; 128], &[7; 20_000]] {
+        let mut buf = Vec::new();
+        buf.write_vbytes(payload).unwrap();
+        assert_eq!(buf, prefixed(payload.len() as u64, payload));
+        assert_eq!(decode_vbytes(&buf, payload.len()), Ok((payload, buf.len())));
+        let mut reader = &buf[..];
+        assert_eq!(reader.read_vbytes(payload.len()).unwrap(), payload);
+        assert!(reader.is_empty());
+
+        buf.push(0xff);
+        assert_eq!(
+            decode_vbytes(&buf, usize::MAX),
+            Ok((payload, buf.len() - 1))
+        );
+    }
+
+    for s in ["", "fastvlq", "ünïcödé ✓", &"x".repeat(300)] {
+        let mut buf = Vec::new();
+        buf.write_vstr(s).unwrap();
+        assert_eq!(buf, prefixed(s.len() as u64, s.as_bytes()));
+        assert_eq!(decode_vstr(&buf, s.len()), Ok((s, buf.len())));
+        let mut reader = &buf[..];
+        assert_eq!(reader.read_vstring(s.len()).unwrap(), s);
+        assert!(reader.is_empty());
+    }
+}
+
+#[test]
+fn length_limit() {
+    let buf = prefixed(5, b"hello");
+    assert_eq!(decode_vbytes(&buf, 5), Ok((&b"hello"[..], 6)));
+    let exceeded = DecodeError::LengthLimitExceeded { len: 5, max: 4 };
+    assert_eq!(decode_vbytes(&buf, 4), Err(exceeded));
+    assert_eq!(decode_vstr(&buf, 4), Err(exceeded));
+
+    let err = (&buf[..]).read_vbytes(4).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(decode_error(&err), exceeded);
+    let err = (&buf[..]).read_vstring(4).unwrap_err();
+    assert_eq!(decode_error(&err), exceeded);
+
+    // The limit is checked before the payload is looked at
+    let buf = prefixed(u64::MAX, b"");
+    let exceeded = DecodeError::LengthLimitExceeded {
+        len: u64::MAX,
+        max: 1 << 20,
+    };
+    assert_eq!(decode_vbytes(&buf, 1 << 20), Err(exceeded));
+    let err = (&buf[..]).read_vbytes(1 << 20).unwrap_err();
+    assert_eq!(decode_error(&err), exceeded);
+}
+
+#[test]
+fn truncated() {
+    let buf = prefixed(5, b"hello");
+    for len in 0..buf.len() {
+        assert_eq!(
+            decode_vbytes(&buf[..len], 5),
+            Err(DecodeError::UnexpectedEof)
+        );
+        assert_eq!(decode_vstr(&buf[..len], 5), Err(DecodeError::UnexpectedEof));
+        let err = (&buf[..len]).read_vbytes(5).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        let err = (&buf[..len]).read_vstring(5).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
+
+#[test]
+fn huge_prefix_on_a_short_stream() {
+    // Trusting the prefix would try to allocate 2^64 - 1 bytes and abort
+    let buf = prefixed(u64::MAX, b"short");
+    assert_eq!(
+        decode_vbytes(&buf, usize::MAX),
+        Err(DecodeError::UnexpectedEof)
+    );
+    let err = (&buf[..]).read_vbytes(usize::MAX).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    let err = (&buf[..]).read_vstring(usize::MAX).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn invalid_utf8() {
+    for payload in [&[0xff][..], b"ok\xc3", b"\xed\xa0\x80"] {
+        let buf = prefixed(payload.len() as u64, payload);
+        // Byte strings don't care
+        assert_eq!(decode_vbytes(&buf, 16), Ok((payload, buf.len())));
+
+        let expected = std::str::from_utf8(payload).unwrap_err();
+        assert_eq!(
+            decode_vstr(&buf, 16),
+            Err(DecodeError::InvalidUtf8(expected))
+        );
+        let err = (&buf[..]).read_vstring(16).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(decode_error(&err), DecodeError::InvalidUtf8(expected));
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use fastvlq::{AsyncReadVlqExt, AsyncWriteVlqExt, DecodeError};
+
+    use super::{decode_error, prefixed};
+
+    /// Run a future that never waits, as reads from slices and writes to vectors don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    #[test]
+    fn matches_sync() {
+        let mut buf = Vec::new();
+        ready(AsyncWriteVlqExt::write_vbytes(&mut buf, &[1, 2, 3])).unwrap();
+        ready(AsyncWriteVlqExt::write_vstr(&mut buf, "ünïcödé")).unwrap();
+        assert_eq!(
+            buf,
+            [prefixed(3, &[1, 2, 3]), prefixed(11, "ünïcödé".as_bytes())].concat()
+        );
+        let mut reader = &buf[..];
+        assert_eq!(ready(reader.read_vbytes(3)).unwrap(), [1, 2, 3]);
+        assert_eq!(ready(reader.read_vstring(11)).unwrap(), "ünïcödé");
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let buf = prefixed(5, b"hello");
+        let err = ready((&mut &buf[..]).read_vbytes(4)).unwrap_err();
+        assert_eq!(
+            decode_error(&err),
+            DecodeError::LengthLimitExceeded { len: 5, max: 4 }
+        );
+
+        let buf = prefixed(u64::MAX, b"short");
+        let err = ready((&mut &buf[..]).read_vbytes(usize::MAX)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        let err = ready((&mut &buf[..]).read_vstring(usize::MAX)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let buf = prefixed(1, &[0xff]);
+        let err = ready((&mut &buf[..]).read_vstring(1)).unwrap_err();
+        assert!(matches!(decode_error(&err), DecodeError::InvalidUtf8(_)));
+    }
+}
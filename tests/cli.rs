@@ -0,0 +1,279 @@
+//! Run the `fastvlq` binary's `encode`, `decode`, `len` and `dump` subcommands.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fastvlq"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Stdout of a run that must succeed.
+fn ok(args: &[&str]) -> String {
+    let output = run(args, &[]);
+    assert!(
+        output.status.success(),
+        "{args:?}: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Stdout of a `dump` of `input`, which must fail exactly when it flags an invalid encoding.
+fn dump(args: &[&str], input: &[u8], invalid: usize) -> String {
+    let output = run(&[&["dump"], args].concat(), input);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    match invalid {
+        0 => assert!(output.status.success(), "{stderr}"),
+        1 => assert_eq!(stderr, "error: 1 invalid encoding\n"),
+        n => assert_eq!(stderr, format!("error: {n} invalid encodings\n")),
+    }
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Stderr of a run that must fail, along with its stdout.
+fn err(args: &[&str]) -> (String, String) {
+    let output = run(args, &[]);
+    assert!(!output.status.success(), "{args:?} succeeded");
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn encode() {
+    assert_eq!(
+        ok(&["encode", "0", "127", "128", "300"]),
+        "80\nff\n4000\n40ac\n"
+    );
+    assert_eq!(
+        ok(&["encode", "-t", "vi32", "--", "-1", "-65"]),
+        "81\n4001\n"
+    );
+    assert_eq!(
+        ok(&["encode", "-f", "json", "300"]),
+        "[\n  {\"value\": \"300\", \"len\": 2, \"hex\": \"40ac\"}\n]\n"
+    );
+    assert_eq!(
+        ok(&["encode", "-t", "vu128-v2", &u128::MAX.to_string()]),
+        "00fffefefdfbf7efdfbf7dfbf7efdfbf7f\n"
+    );
+
+    let raw = run(&["encode", "-f", "raw", "1", "300"], &[]);
+    assert!(raw.status.success());
+    assert_eq!(raw.stdout, [0x81, 0x40, 0xac]);
+}
+
+#[test]
+fn encode_rejects_bad_numbers() {
+    let (_, stderr) = err(&["encode", "1", "x"]);
+    assert!(stderr.contains("invalid number \"x\""), "{stderr}");
+    let (_, stderr) = err(&["encode", "-t", "vu32", "4294967296"]);
+    assert!(stderr.contains("invalid number"), "{stderr}");
+}
+
+#[test]
+fn decode() {
+    assert_eq!(ok(&["decode", "80", "ff", "40ac"]), "0\n127\n300\n");
+    assert_eq!(ok(&["decode", "0x80ff_40ac"]), "0\n127\n300\n");
+    assert_eq!(
+        ok(&["decode", "-t", "vi64", "-f", "json", "8140ac"]),
+        concat!(
+            "[\n",
+            "  {\"offset\": 0, \"len\": 1, \"value\": \"-1\"},\n",
+            "  {\"offset\": 1, \"len\": 2, \"value\": \"150\"}\n",
+            "]\n"
+        )
+    );
+
+    let stdin = run(&["decode", "-t", "vu32"], &[0x81, 0x40, 0xac]);
+    assert!(stdin.status.success());
+    assert_eq!(stdin.stdout, b"1\n300\n");
+}
+
+#[test]
+fn decode_reports_the_failing_offset() {
+    // Values before the failure are still printed
+    let (stdout, stderr) = err(&["decode", "8140"]);
+    assert_eq!(stdout, "1\n");
+    assert_eq!(stderr, "error: offset 1: unexpected end of input\n");
+
+    let (_, stderr) = err(&["decode", "-t", "vu32", "08efdfbf80"]);
+    assert_eq!(stderr, "error: offset 0: value overflows the target type\n");
+
+    // The v2 reader's errors keep their kind rather than all becoming overflow
+    let (_, stderr) = err(&["decode", "-t", "vu128-v2", "01"]);
+    assert_eq!(stderr, "error: offset 0: unexpected end of input\n");
+    let max = format!("00{}", "ff".repeat(16));
+    let (_, stderr) = err(&["decode", "-t", "vu128-v2", &max]);
+    assert_eq!(stderr, "error: offset 0: value overflows the target type\n");
+
+    let (_, stderr) = err(&["decode", "8"]);
+    assert!(stderr.contains("odd number of hex digits"), "{stderr}");
+}
+
+#[test]
+fn len_of_numbers() {
+    assert_eq!(
+        ok(&["len", "0", "128", "16512"]),
+        "0\t1\n128\t2\n16512\t3\n"
+    );
+    assert_eq!(ok(&["len", "-t", "vf64", "1", "100"]), "1\t1\n100\t3\n");
+    assert_eq!(
+        ok(&["len", "-f", "json", "300"]),
+        "[\n  {\"value\": \"300\", \"len\": 2}\n]\n"
+    );
+}
+
+#[test]
+fn len_classes() {
+    assert_eq!(
+        ok(&["len", "-t", "vu32"]),
+        concat!(
+            "bytes\trange\n",
+            "1\t0..=127\n",
+            "2\t128..=16511\n",
+            "3\t16512..=2113663\n",
+            "4\t2113664..=270549119\n",
+            "5\t270549120..=4294967295\n",
+        )
+    );
+    assert_eq!(
+        ok(&["len", "-t", "vi32"]).lines().nth(2),
+        Some("2\t-8256..=-65, 64..=8255")
+    );
+
+    // Lengths fall back and forth between the extended and raw `Vu128` forms
+    let vu128 = ok(&["len", "-t", "vu128"]);
+    let lens = vu128
+        .lines()
+        .skip(1)
+        .map(|line| line.split('\t').next().unwrap().parse().unwrap())
+        .collect::<Vec<u8>>();
+    assert_eq!(
+        lens,
+        [
+            1, 2, 3, 4, 5, 6, 7, 8, 18, 9, 10, 18, 11, 18, 12, 18, 13, 18, 14, 18, 15, 18, 16, 18
+        ]
+    );
+    assert!(vu128.ends_with(&format!("..={}\n", u128::MAX)));
+
+    let (_, stderr) = err(&["len", "-t", "vf32"]);
+    assert!(stderr.contains("no length classes"), "{stderr}");
+}
+
+#[test]
+fn dump_schema() {
+    // A record of `vu32,vi64,bytes*` whose byte strings repeat to the end of the input
+    assert_eq!(
+        dump(&["-s", "vu32, vi64, bytes*"], b"\x40\xac\x81\x82hi\x80", 0),
+        concat!(
+            "offset\tlen\tfield\tprefix\tbytes\tvalue\tstatus\n",
+            "0\t2\tvu32\t01\t40ac\t300\tok\n",
+            "2\t1\tvi64\t1\t81\t-1\tok\n",
+            "3\t3\tbytes\t1\t82 6869\t6869\tok\n",
+            "6\t1\tbytes\t1\t80\t\tok\n",
+        )
+    );
+
+    // Without a repeated field the whole record repeats
+    let text = dump(&["-s", "vu32,str"], b"\x81\x82hi\x82\x80", 0);
+    let fields = text
+        .lines()
+        .skip(1)
+        .map(|line| line.split('\t').nth(5).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, ["1", "\"hi\"", "2", "\"\""]);
+
+    // `-t` is a schema of that one type, repeated
+    assert_eq!(dump(&["-t", "vi32"], b"\x81\x40\x01", 0).lines().count(), 3);
+    assert_eq!(
+        dump(&["-s", "vu32,str", "-f", "json"], b"\x81\x82hi", 0),
+        concat!(
+            "[\n",
+            "  {\"offset\": 0, \"len\": 1, \"field\": \"vu32\", \"prefix\": \"1\", \"bytes\": \"81\", \"value\": \"1\", \"status\": \"ok\"},\n",
+            "  {\"offset\": 1, \"len\": 3, \"field\": \"str\", \"prefix\": \"1\", \"bytes\": \"826869\", \"value\": \"hi\", \"status\": \"ok\"}\n",
+            "]\n"
+        )
+    );
+}
+
+#[test]
+fn dump_rejects_bad_schemas() {
+    let (_, stderr) = err(&["dump", "-s", "bytes*,vu32"]);
+    assert!(
+        stderr.contains("only the last field can repeat, not \"bytes\""),
+        "{stderr}"
+    );
+    let (_, stderr) = err(&["dump", "-s", "vu32,nope"]);
+    assert!(stderr.contains("unknown field \"nope\""), "{stderr}");
+    let (_, stderr) = err(&["dump", "-s", "vu32", "-t", "vi32"]);
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+}
+
+#[test]
+fn dump_record_ends_early() {
+    assert_eq!(
+        dump(&["-s", "vu32,vi64,str"], b"\x81\x82", 1),
+        concat!(
+            "offset\tlen\tfield\tprefix\tbytes\tvalue\tstatus\n",
+            "0\t1\tvu32\t1\t81\t1\tok\n",
+            "1\t1\tvi64\t1\t82\t1\tok\n",
+            "2\t0\tstr\t\t\t-\tinvalid: record ends early\n",
+        )
+    );
+
+    // Running out between records, or during a repeated field, is not an early end
+    dump(&["-s", "vu32,vi64"], b"\x81\x82\x83\x84", 0);
+    dump(&["-s", "vu32,vi64*"], b"\x81", 0);
+}
+
+#[test]
+fn dump_flags_invalid_encodings() {
+    let statuses = |text: &str| {
+        text.lines()
+            .skip(1)
+            .map(|line| {
+                let columns = line.split('\t').collect::<Vec<_>>();
+                format!("{} {} {}", columns[0], columns[1], columns[6])
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // An overflowing payload and a prefix past `Vu32`'s five bytes each skip the length
+    // they claim, and the walk carries on after them
+    let text = dump(&["-t", "vu32"], b"\x08\xef\xdf\xbf\x80\x04\0\0\0\0\x81", 2);
+    assert_eq!(
+        statuses(&text),
+        [
+            "0 5 invalid: value overflows the target type",
+            "5 5 invalid: value overflows the target type",
+            "10 1 ok",
+        ]
+    );
+
+    // A raw 18-byte `Vu128` of a value with a shorter form decodes but is flagged
+    let mut raw = vec![0; 18];
+    raw[17] = 1;
+    raw.push(0x81);
+    let text = dump(&["-t", "vu128"], &raw, 0);
+    assert_eq!(statuses(&text), ["0 18 non-canonical", "18 1 ok"]);
+    assert!(text.lines().nth(1).unwrap().contains("\t1\tnon-canonical"));
+
+    let text = dump(&["-s", "str"], b"\x82\xffh\x84hi", 2);
+    assert_eq!(
+        statuses(&text),
+        [
+            "0 3 invalid: invalid UTF-8: invalid utf-8 sequence of 1 bytes from index 0",
+            "3 3 invalid: length 4 exceeds the 2 bytes left",
+        ]
+    );
+}
@@ -0,0 +1,132 @@
+//! Check that comparing encodings agrees with comparing the values they hold.
+
+use fastvlq::{
+    cmp_encoded_vi32, cmp_encoded_vi64, cmp_encoded_vi128, cmp_encoded_vu32, cmp_encoded_vu64,
+    cmp_encoded_vu128, cmp_encoded_vu128_v2, encode_vi32, encode_vi64, encode_vi128, encode_vu32,
+    encode_vu64, encode_vu128, encode_vu128_v2,
+};
+use proptest::prelude::*;
+
+/// First value of the `len`-byte class, matching the crate's `offset!`.
+fn offset(len: u32) -> u128 {
+    // The extended classes are spaced one bit wider than the standard ones
+    (1..len)
+        .map(|k| {
+            if k < 9 {
+                1u128 << (7 * k)
+            } else {
+                1u128 << (7 * k + 1)
+            }
+        })
+        .sum()
+}
+
+/// Values within a small distance of a point where an encoding changes length or form.
+fn near_boundary() -> impl Strategy<Value = u128> {
+    let mut points: Vec<u128> = (1..=17).map(offset).collect();
+    // `Vu128` uses the raw 18-byte form between `offset(9)` and the first of these,
+    // and from each of the rest up to the next class
+    points.push(offset(9) + (1 << 63));
+    points.extend((10..=16).map(|len| offset(len) + (1 << (7 * len))));
+    points.push(u128::MAX);
+    (prop::sample::select(points), -4i8..=4).prop_map(|(n, d)| n.wrapping_add_signed(d as i128))
+}
+
+/// Uniform values are almost all maximum length; spread them over every width.
+fn any_width() -> impl Strategy<Value = u128> {
+    (0u32..=128, any::<u128>()).prop_map(|(bits, n)| n.checked_shr(bits).unwrap_or(0))
+}
+
+fn value() -> impl Strategy<Value = u128> {
+    prop_oneof![near_boundary(), any_width()]
+}
+
+/// Map an unsigned value to the signed value whose zigzag form it is, so signed values
+/// also land near the boundaries.
+fn unzigzag(n: u128) -> i128 {
+    (n >> 1) as i128 ^ -((n & 1) as i128)
+}
+
+fn check(a: u128, b: u128) {
+    let (x, y) = (a as u32, b as u32);
+    assert_eq!(
+        cmp_encoded_vu32(encode_vu32(x).as_slice(), encode_vu32(y).as_slice()),
+        x.cmp(&y)
+    );
+    let (x, y) = (a as u64, b as u64);
+    assert_eq!(
+        cmp_encoded_vu64(encode_vu64(x).as_slice(), encode_vu64(y).as_slice()),
+        x.cmp(&y)
+    );
+    assert_eq!(
+        cmp_encoded_vu128(encode_vu128(a).as_slice(), encode_vu128(b).as_slice()),
+        a.cmp(&b)
+    );
+    assert_eq!(
+        cmp_encoded_vu128_v2(encode_vu128_v2(a).as_slice(), encode_vu128_v2(b).as_slice()),
+        a.cmp(&b)
+    );
+
+    let (x, y) = (
+        unzigzag(a as u32 as u128) as i32,
+        unzigzag(b as u32 as u128) as i32,
+    );
+    assert_eq!(
+        cmp_encoded_vi32(encode_vi32(x).as_slice(), encode_vi32(y).as_slice()),
+        x.cmp(&y)
+    );
+    let (x, y) = (
+        unzigzag(a as u64 as u128) as i64,
+        unzigzag(b as u64 as u128) as i64,
+    );
+    assert_eq!(
+        cmp_encoded_vi64(encode_vi64(x).as_slice(), encode_vi64(y).as_slice()),
+        x.cmp(&y)
+    );
+    let (x, y) = (unzigzag(a), unzigzag(b));
+    assert_eq!(
+        cmp_encoded_vi128(encode_vi128(x).as_slice(), encode_vi128(y).as_slice()),
+        x.cmp(&y)
+    );
+}
+
+#[test]
+fn raw_form_neighbours() {
+    // The last value in the raw 18-byte form against the first 9-byte one, and both
+    // against the 8-byte class and the raw values above the 10-byte class
+    let nine_byte_min = offset(9) + (1 << 63);
+    assert_eq!(encode_vu128(nine_byte_min - 1).len(), 18);
+    assert_eq!(encode_vu128(nine_byte_min).len(), 9);
+    check(nine_byte_min - 1, nine_byte_min);
+    check(offset(9) - 1, nine_byte_min - 1);
+    check(offset(9), offset(9) - 1);
+    let ten_byte_raw = offset(10) + (1 << 70);
+    check(nine_byte_min - 1, ten_byte_raw);
+    check(ten_byte_raw - 1, ten_byte_raw);
+    check(u128::MAX, nine_byte_min - 1);
+}
+
+#[test]
+fn equal_values() {
+    for n in [
+        0,
+        offset(9) - 1,
+        offset(9),
+        offset(9) + (1 << 63),
+        u128::MAX,
+    ] {
+        check(n, n);
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_value_order(a in value(), b in value()) {
+        check(a, b);
+    }
+
+    #[test]
+    fn matches_value_order_nearby(a in near_boundary(), d in -300i16..=300) {
+        check(a, a.wrapping_add_signed(d as i128));
+    }
+}
@@ -0,0 +1,104 @@
+//! Round-trip every `IntCodec` directly and through the reader and writer extensions.
+
+use fastvlq::{
+    DecodeError, EncodeError, FixedBe, FixedBeN, FixedLe, FixedLeN, IntCodec, Leb128Codec,
+    ReadVlqExt, Vu32Codec, Vu64Codec, Vu128Codec, Vu128V2Codec, WriteVlqExt,
+};
+use proptest::prelude::*;
+
+fn round_trip<C: IntCodec>(n: u64) {
+    let mut buf = [0u8; 32];
+    let len = C::encode_u64(n, &mut buf).unwrap();
+    assert_eq!(len, C::encoded_len(n), "{n:#x}");
+    assert!(len <= C::max_len());
+    assert_eq!(C::decode_u64(&buf[..len]), Ok((n, len)), "{n:#x}");
+    assert_eq!(
+        C::decode_u64(&buf[..len - 1]),
+        Err(DecodeError::UnexpectedEof)
+    );
+
+    let mut out = Vec::new();
+    out.write_u64_with::<C>(n).unwrap();
+    assert_eq!(out, buf[..len]);
+    let mut reader = &out[..];
+    assert_eq!(reader.read_u64_with::<C>().unwrap(), n);
+    assert!(reader.is_empty());
+}
+
+fn round_trip_all(n: u64) {
+    round_trip::<Vu64Codec>(n);
+    round_trip::<Vu128Codec>(n);
+    round_trip::<Vu128V2Codec>(n);
+    round_trip::<FixedBe>(n);
+    round_trip::<FixedLe>(n);
+    round_trip::<Leb128Codec>(n);
+    if let Ok(n) = u32::try_from(n) {
+        round_trip::<Vu32Codec>(n as u64);
+    }
+}
+
+#[test]
+fn boundaries() {
+    for k in 0..64 {
+        for n in [(1u64 << k) - 1, 1 << k, (1 << k) + 1] {
+            round_trip_all(n);
+        }
+    }
+    round_trip_all(u64::MAX);
+}
+
+#[test]
+fn vu32_codec_overflow() {
+    let mut buf = [0u8; 5];
+    assert_eq!(
+        Vu32Codec::encode_u64(u32::MAX as u64, &mut buf),
+        Ok(Vu32Codec::max_len())
+    );
+    assert_eq!(
+        Vu32Codec::encode_u64(u32::MAX as u64 + 1, &mut buf),
+        Err(EncodeError::Overflow)
+    );
+    let err = Vec::new()
+        .write_u64_with::<Vu32Codec>(u64::MAX)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    // A `Vu64` of 6 bytes or more is not a `Vu32`
+    let mut buf = [0u8; 9];
+    Vu64Codec::encode_u64(u64::MAX, &mut buf).unwrap();
+    assert_eq!(Vu32Codec::decode_u64(&buf), Err(DecodeError::Overflow));
+}
+
+#[test]
+fn fixed_unit_structs() {
+    // The 8-byte codecs stay plain unit structs usable as values
+    let (be, le) = (FixedBe, FixedLe);
+    assert_eq!(format!("{be:?} {le:?}"), "FixedBe FixedLe");
+    let mut buf = [0u8; 8];
+    FixedBe::encode_u64(0x0102, &mut buf).unwrap();
+    assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+    FixedLe::encode_u64(0x0102, &mut buf).unwrap();
+    assert_eq!(buf, [0x02, 0x01, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn narrow_fixed_widths() {
+    round_trip::<FixedBeN<1>>(0xff);
+    round_trip::<FixedLeN<3>>(0xff_ffff);
+    let mut buf = [0u8; 3];
+    assert_eq!(
+        FixedLeN::<3>::encode_u64(0x100_0000, &mut buf),
+        Err(EncodeError::Overflow)
+    );
+    FixedBeN::<3>::encode_u64(0x01_0203, &mut buf).unwrap();
+    assert_eq!(buf, [0x01, 0x02, 0x03]);
+    FixedLeN::<3>::encode_u64(0x01_0203, &mut buf).unwrap();
+    assert_eq!(buf, [0x03, 0x02, 0x01]);
+}
+
+proptest! {
+    #[test]
+    fn round_trips(n: u64, shift in 0u32..64) {
+        round_trip_all(n >> shift);
+    }
+}
@@ -0,0 +1,99 @@
+//! Check Bitcoin CompactSize against the class boundaries of the format, in both the
+//! lenient and strict decoders.
+
+use fastvlq::DecodeError;
+use fastvlq::compact_size::{
+    CompactSize, ReadCompactSizeExt, WriteCompactSizeExt, decode_compact_size,
+    decode_compact_size_strict,
+};
+
+/// The first and last value of every length class.
+const BOUNDARIES: &[(u64, &[u8])] = &[
+    (0, &[0x00]),
+    (0xfc, &[0xfc]),
+    (0xfd, &[0xfd, 0xfd, 0x00]),
+    (0xffff, &[0xfd, 0xff, 0xff]),
+    (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+    (0xffff_ffff, &[0xfe, 0xff, 0xff, 0xff, 0xff]),
+    (
+        0x1_0000_0000,
+        &[0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00],
+    ),
+    (
+        u64::MAX,
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    ),
+];
+
+/// Values in a wider form than they need, one for each wide marker.
+const NON_CANONICAL: &[(u64, &[u8])] = &[
+    (0xfc, &[0xfd, 0xfc, 0x00]),
+    (0xffff, &[0xfe, 0xff, 0xff, 0x00, 0x00]),
+    (
+        0xffff_ffff,
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00],
+    ),
+];
+
+fn read_error(result: std::io::Result<u64>) -> DecodeError {
+    let err = result.unwrap_err();
+    *err.get_ref()
+        .unwrap()
+        .downcast_ref::<DecodeError>()
+        .unwrap()
+}
+
+#[test]
+fn class_boundaries() {
+    for &(value, encoding) in BOUNDARIES {
+        let vlq = CompactSize::new(value);
+        assert_eq!(vlq.as_slice(), encoding, "{value:#x}");
+        assert_eq!(CompactSize::encoded_len(value) as usize, encoding.len());
+        assert_eq!(
+            CompactSize::len_from_first_byte(encoding[0]) as usize,
+            encoding.len()
+        );
+        assert_eq!(decode_compact_size(encoding), Ok((value, encoding.len())));
+        assert_eq!(
+            decode_compact_size_strict(encoding),
+            Ok((value, encoding.len()))
+        );
+
+        let mut buf = Vec::new();
+        buf.write_compact_size(value).unwrap();
+        assert_eq!(buf, encoding);
+        assert_eq!((&buf[..]).read_compact_size().unwrap(), value);
+        assert_eq!((&buf[..]).read_compact_size_strict().unwrap(), value);
+    }
+}
+
+#[test]
+fn non_canonical() {
+    for &(value, encoding) in NON_CANONICAL {
+        assert_eq!(decode_compact_size(encoding), Ok((value, encoding.len())));
+        assert_eq!((&encoding[..]).read_compact_size().unwrap(), value);
+
+        assert_eq!(
+            decode_compact_size_strict(encoding),
+            Err(DecodeError::NonCanonical)
+        );
+        assert_eq!(
+            read_error((&encoding[..]).read_compact_size_strict()),
+            DecodeError::NonCanonical
+        );
+    }
+}
+
+#[test]
+fn truncated() {
+    for &(_, encoding) in BOUNDARIES {
+        for len in 0..encoding.len() {
+            let buf = &encoding[..len];
+            assert_eq!(decode_compact_size(buf), Err(DecodeError::UnexpectedEof));
+            assert_eq!(
+                decode_compact_size_strict(buf),
+                Err(DecodeError::UnexpectedEof)
+            );
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Round-trip decimals across every scale and check the scale and mantissa limits.
+
+use fastvlq::{
+    DecodeError, ReadVlqExt, VDecimal, Vi128, Vu32, WriteVlqExt, decode_vdecimal, encode_vdecimal,
+};
+use rust_decimal::Decimal;
+
+/// Largest mantissa a `Decimal` holds, 2^96 - 1.
+const MAX_MANTISSA: i128 = (1 << 96) - 1;
+
+fn decode_error(err: &std::io::Error) -> DecodeError {
+    *err.get_ref()
+        .and_then(|inner| inner.downcast_ref::<DecodeError>())
+        .unwrap()
+}
+
+/// Encode a scale and mantissa without going through `Decimal`, so either can be out
+/// of range.
+fn raw(scale: u32, mantissa: i128) -> Vec<u8> {
+    [Vu32::new(scale).as_slice(), Vi128::new(mantissa).as_slice()].concat()
+}
+
+fn round_trip(n: Decimal) {
+    let encoded = encode_vdecimal(n);
+    assert_eq!(encoded.get(), n);
+    assert_eq!(encoded.get().scale(), n.scale());
+    assert_eq!(encoded.len(), VDecimal::encoded_len(n));
+    let mut buf = encoded.as_slice().to_vec();
+    assert_eq!(buf, raw(n.scale(), n.mantissa()));
+    assert_eq!(decode_vdecimal(&buf), Ok((n, buf.len())));
+
+    let mut written = Vec::new();
+    written.write_vdecimal(n).unwrap();
+    assert_eq!(written, buf);
+    let mut reader = &written[..];
+    let read = reader.read_vdecimal().unwrap();
+    assert_eq!((read, read.scale()), (n, n.scale()));
+    assert!(reader.is_empty());
+
+    buf.push(0xff);
+    assert_eq!(decode_vdecimal(&buf), Ok((n, buf.len() - 1)));
+}
+
+#[test]
+fn every_scale() {
+    for scale in 0..=Decimal::MAX_SCALE {
+        for mantissa in [
+            0,
+            1,
+            -1,
+            12_345,
+            -987_654_321,
+            i64::MAX as i128,
+            MAX_MANTISSA,
+            -MAX_MANTISSA,
+        ] {
+            round_trip(Decimal::from_i128_with_scale(mantissa, scale));
+        }
+    }
+    round_trip(Decimal::MAX);
+    round_trip(Decimal::MIN);
+    round_trip("0.0000000000000000000000000001".parse().unwrap());
+}
+
+#[test]
+fn lengths() {
+    // The scale always takes one byte
+    assert_eq!(encode_vdecimal(Decimal::ZERO).as_slice(), [0x80, 0x80]);
+    assert_eq!(
+        encode_vdecimal(Decimal::new(1, 28)).as_slice()[0],
+        0x80 | 28
+    );
+    assert!(encode_vdecimal(Decimal::MAX).len() as usize <= VDecimal::MAX_LEN);
+}
+
+#[test]
+fn scale_past_max() {
+    assert_eq!(decode_vdecimal(&raw(28, 1)), Ok((Decimal::new(1, 28), 2)));
+    for scale in [29, 127, u32::MAX] {
+        let buf = raw(scale, 1);
+        assert_eq!(decode_vdecimal(&buf), Err(DecodeError::InvalidScale(scale)));
+        let err = (&buf[..]).read_vdecimal().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(decode_error(&err), DecodeError::InvalidScale(scale));
+    }
+}
+
+#[test]
+fn mantissa_past_96_bits() {
+    for mantissa in [MAX_MANTISSA + 1, -MAX_MANTISSA - 1, i128::MAX, i128::MIN] {
+        let buf = raw(0, mantissa);
+        assert_eq!(
+            decode_vdecimal(&buf),
+            Err(DecodeError::Overflow),
+            "{mantissa}"
+        );
+        let err = (&buf[..]).read_vdecimal().unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+    }
+}
+
+#[test]
+fn truncated() {
+    let buf = encode_vdecimal(Decimal::MAX);
+    let buf = buf.as_slice();
+    for len in 0..buf.len() {
+        assert_eq!(
+            decode_vdecimal(&buf[..len]),
+            Err(DecodeError::UnexpectedEof)
+        );
+        let err = (&buf[..len]).read_vdecimal().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use fastvlq::{AsyncReadVlqExt, AsyncWriteVlqExt, DecodeError, encode_vdecimal};
+    use rust_decimal::Decimal;
+
+    use super::{decode_error, raw};
+
+    /// Run a future that never waits, as reads from slices and writes to vectors don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    #[test]
+    fn matches_sync() {
+        for scale in 0..=Decimal::MAX_SCALE {
+            let n = Decimal::from_i128_with_scale(-123_456_789, scale);
+            let mut buf = Vec::new();
+            ready(AsyncWriteVlqExt::write_vdecimal(&mut buf, n)).unwrap();
+            assert_eq!(buf, encode_vdecimal(n).as_slice());
+            let read = ready((&mut &buf[..]).read_vdecimal()).unwrap();
+            assert_eq!((read, read.scale()), (n, scale));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = ready((&mut &raw(29, 1)[..]).read_vdecimal()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::InvalidScale(29));
+        let err = ready((&mut &raw(0, 1 << 96)[..]).read_vdecimal()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+    }
+}
@@ -0,0 +1,187 @@
+//! Round-trip derived `VlqEncode` and `VlqDecode` implementations.
+
+use fastvlq::{DecodeError, EncodeError, VlqDecode, VlqEncode};
+
+#[derive(Debug, PartialEq, VlqEncode, VlqDecode)]
+struct Record {
+    id: u64,
+    delta: i32,
+    #[vlq(vi64)]
+    offset: i32,
+    #[vlq(vu32)]
+    count: u64,
+    #[vlq(fixed)]
+    signed: i16,
+    #[vlq(fixed)]
+    wide: i64,
+    name: String,
+    flag: bool,
+}
+
+#[derive(Debug, PartialEq, VlqEncode, VlqDecode)]
+struct Pair<A, B>(A, B);
+
+#[derive(Debug, PartialEq, VlqEncode, VlqDecode)]
+#[repr(u32)]
+enum Shape {
+    Empty,
+    Circle { radius: u32 },
+    Line(i64, i64),
+    Tagged = 10,
+    AfterTagged(#[vlq(fixed)] i8),
+    Last = 1000,
+}
+
+fn round_trip<T: VlqEncode + VlqDecode + PartialEq + std::fmt::Debug>(value: &T) -> Vec<u8> {
+    let buf = value.encode_vlq().unwrap();
+    let (decoded, len) = T::decode_vlq(&buf).unwrap();
+    assert_eq!(&decoded, value);
+    assert_eq!(len, buf.len());
+    buf
+}
+
+fn record() -> Record {
+    Record {
+        id: 300,
+        delta: -1,
+        offset: i32::MIN,
+        count: u32::MAX as u64,
+        signed: -2,
+        wide: i64::MIN,
+        name: "fastvlq".into(),
+        flag: true,
+    }
+}
+
+#[test]
+fn struct_fields() {
+    let buf = round_trip(&record());
+    let mut expected = Vec::new();
+    expected.extend(300u64.encode_vlq().unwrap());
+    expected.extend((-1i32).encode_vlq().unwrap());
+    expected.extend((i32::MIN as i64).encode_vlq().unwrap());
+    expected.extend(u32::MAX.encode_vlq().unwrap());
+    // Fixed fields are big-endian two's complement at the field's width
+    expected.extend([0xff, 0xfe]);
+    expected.extend([0x80, 0, 0, 0, 0, 0, 0, 0]);
+    expected.extend("fastvlq".to_string().encode_vlq().unwrap());
+    expected.push(0x81);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn narrowed_fields_overflow() {
+    let mut value = record();
+    value.count = u32::MAX as u64 + 1;
+    assert_eq!(value.encode_vlq(), Err(EncodeError::Overflow));
+
+    // A `vi64` field holding a value past its own width fails to decode
+    let mut buf = round_trip(&record());
+    let offset = 300u64.encode_vlq().unwrap().len() + (-1i32).encode_vlq().unwrap().len();
+    let wide = (i64::MIN).encode_vlq().unwrap();
+    let old = (i32::MIN as i64).encode_vlq().unwrap();
+    buf.splice(offset..offset + old.len(), wide);
+    assert_eq!(Record::decode_vlq(&buf), Err(DecodeError::Overflow));
+}
+
+#[test]
+fn generics() {
+    round_trip(&Pair(1u8, -1i128));
+    round_trip(&Pair(String::from("a"), Pair(true, 2.5f64)));
+    assert_eq!(
+        round_trip(&Pair(1u32, 2u32)),
+        [1u32.encode_vlq().unwrap(), 2u32.encode_vlq().unwrap()].concat()
+    );
+}
+
+#[test]
+fn enum_variants() {
+    for (shape, discriminant) in [
+        (Shape::Empty, 0u32),
+        (Shape::Circle { radius: 7 }, 1),
+        (Shape::Line(-3, i64::MAX), 2),
+        (Shape::Tagged, 10),
+        (Shape::AfterTagged(-1), 11),
+        (Shape::Last, 1000),
+    ] {
+        let buf = round_trip(&shape);
+        let prefix = discriminant.encode_vlq().unwrap();
+        assert_eq!(buf[..prefix.len()], prefix, "{shape:?}");
+    }
+    assert_eq!(
+        Shape::AfterTagged(-1).encode_vlq().unwrap(),
+        [11u32.encode_vlq().unwrap(), vec![0xff]].concat()
+    );
+}
+
+#[test]
+fn invalid_discriminant() {
+    for discriminant in [3u32, 9, 12, 999, 1001, u32::MAX] {
+        let buf = discriminant.encode_vlq().unwrap();
+        assert_eq!(
+            Shape::decode_vlq(&buf),
+            Err(DecodeError::InvalidDiscriminant(discriminant))
+        );
+    }
+}
+
+#[test]
+fn invalid_bool() {
+    assert_eq!(bool::decode_vlq(&[0x80]), Ok((false, 1)));
+    assert_eq!(bool::decode_vlq(&[0x81]), Ok((true, 1)));
+    assert_eq!(bool::decode_vlq(&[0x82]), Err(DecodeError::InvalidBool(2)));
+}
+
+#[test]
+fn truncated() {
+    let buf = round_trip(&record());
+    for len in 0..buf.len() {
+        assert_eq!(
+            Record::decode_vlq(&buf[..len]),
+            Err(DecodeError::UnexpectedEof)
+        );
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use fastvlq::{VlqDecode, VlqEncode};
+
+    use super::{Shape, record};
+
+    /// Run a future that never waits, as reads from slices and writes to vectors don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    fn round_trip<T: VlqEncode + VlqDecode + PartialEq + std::fmt::Debug>(value: &T) {
+        let mut buf = Vec::new();
+        ready(value.write_vlq_async(&mut buf)).unwrap();
+        assert_eq!(buf, value.encode_vlq().unwrap());
+        let mut reader = &buf[..];
+        assert_eq!(&ready(T::read_vlq_async(&mut reader)).unwrap(), value);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn matches_sync() {
+        round_trip(&record());
+        round_trip(&Shape::Circle { radius: 7 });
+        round_trip(&Shape::AfterTagged(i8::MIN));
+    }
+
+    #[test]
+    fn invalid_discriminant() {
+        let buf = 5u32.encode_vlq().unwrap();
+        let err = ready(Shape::read_vlq_async(&mut &buf[..])).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,163 @@
+//! Round-trip through `embedded-io` slices and check how reader errors are reported.
+
+use embedded_io::{ErrorKind, ErrorType, Read, ReadExactError, SliceWriteError};
+use fastvlq::{DecodeError, EmbeddedReadVlqExt, EmbeddedWriteVlqExt, ReadError};
+
+/// A reader that hands out `data` and then fails instead of reporting the end.
+struct Failing<'a> {
+    data: &'a [u8],
+}
+
+impl ErrorType for Failing<'_> {
+    type Error = ErrorKind;
+}
+
+impl Read for Failing<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.data.is_empty() {
+            return Err(ErrorKind::BrokenPipe);
+        }
+        self.data.read(buf).map_err(|e| match e {})
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut storage = [0u8; 128];
+    let mut writer = &mut storage[..];
+    writer.write_vu32(u32::MAX).unwrap();
+    writer.write_vi32(i32::MIN).unwrap();
+    writer.write_vu64(u64::MAX).unwrap();
+    writer.write_vi64(-1).unwrap();
+    writer.write_vu128(u128::MAX).unwrap();
+    writer.write_vi128(i128::MIN).unwrap();
+    writer.write_vu64(0).unwrap();
+    let written = 128 - writer.len();
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(fastvlq::Vu32::new(u32::MAX).as_slice());
+    expected.extend_from_slice(fastvlq::Vi32::new(i32::MIN).as_slice());
+    expected.extend_from_slice(fastvlq::Vu64::new(u64::MAX).as_slice());
+    expected.extend_from_slice(fastvlq::Vi64::new(-1).as_slice());
+    expected.extend_from_slice(fastvlq::Vu128::new(u128::MAX).as_slice());
+    expected.extend_from_slice(fastvlq::Vi128::new(i128::MIN).as_slice());
+    expected.push(0x80);
+    assert_eq!(&storage[..written], expected);
+
+    let mut reader = &storage[..written];
+    assert_eq!(reader.read_vu32(), Ok(u32::MAX));
+    assert_eq!(reader.read_vi32(), Ok(i32::MIN));
+    assert_eq!(reader.read_vu64(), Ok(u64::MAX));
+    assert_eq!(reader.read_vi64(), Ok(-1));
+    assert_eq!(reader.read_vu128(), Ok(u128::MAX));
+    assert_eq!(reader.read_vi128(), Ok(i128::MIN));
+    assert_eq!(reader.read_vu64(), Ok(0));
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn full_writer() {
+    let mut storage = [0u8; 4];
+    let mut writer = &mut storage[..];
+    assert_eq!(writer.write_vu64(u64::MAX), Err(SliceWriteError::Full));
+}
+
+#[test]
+fn truncated() {
+    // Running out of bytes is a decode error, not an I/O one
+    assert_eq!(
+        ReadError::<()>::from(ReadExactError::UnexpectedEof),
+        ReadError::Decode(DecodeError::UnexpectedEof)
+    );
+    let eof = ReadError::Decode(DecodeError::UnexpectedEof);
+    let buf = fastvlq::Vu128::new(u128::MAX);
+    let buf = buf.as_slice();
+    for len in 0..buf.len() {
+        assert_eq!((&buf[..len]).read_vu128().unwrap_err(), eof);
+    }
+    let buf = fastvlq::Vu64::new(u64::MAX);
+    let buf = buf.as_slice();
+    for len in 0..buf.len() {
+        assert_eq!((&buf[..len]).read_vu64().unwrap_err(), eof);
+    }
+    let buf = fastvlq::Vu32::new(u32::MAX);
+    let buf = buf.as_slice();
+    for len in 0..buf.len() {
+        assert_eq!((&buf[..len]).read_vi32().unwrap_err(), eof);
+    }
+}
+
+#[test]
+fn reader_failure() {
+    assert_eq!(
+        ReadError::from(ReadExactError::Other(ErrorKind::BrokenPipe)),
+        ReadError::Io(ErrorKind::BrokenPipe)
+    );
+    let buf = fastvlq::Vu64::new(u64::MAX);
+    let mut reader = Failing {
+        data: &buf.as_slice()[..3],
+    };
+    assert_eq!(
+        reader.read_vu64(),
+        Err(ReadError::Io(ErrorKind::BrokenPipe))
+    );
+}
+
+#[test]
+fn overflow() {
+    let buf = fastvlq::Vu64::new(u32::MAX as u64 + 1);
+    assert_eq!(
+        buf.as_slice().read_vu32(),
+        Err(ReadError::Decode(DecodeError::Overflow))
+    );
+    let err = ReadError::<ErrorKind>::Decode(DecodeError::Overflow);
+    assert_eq!(embedded_io::Error::kind(&err), ErrorKind::InvalidData);
+}
+
+#[cfg(feature = "embedded-io-async")]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use fastvlq::{DecodeError, EmbeddedAsyncReadVlqExt, EmbeddedAsyncWriteVlqExt, ReadError};
+
+    /// Run a future that never waits, as reads from and writes to slices don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut storage = [0u8; 64];
+        let mut writer = &mut storage[..];
+        ready(writer.write_vu32(u32::MAX)).unwrap();
+        ready(writer.write_vi64(i64::MIN)).unwrap();
+        ready(writer.write_vi128(-1)).unwrap();
+        ready(writer.write_vu128(u128::MAX)).unwrap();
+        let written = 64 - writer.len();
+
+        let mut reader = &storage[..written];
+        assert_eq!(ready(reader.read_vu32()), Ok(u32::MAX));
+        assert_eq!(ready(reader.read_vi64()), Ok(i64::MIN));
+        assert_eq!(ready(reader.read_vi128()), Ok(-1));
+        assert_eq!(ready(reader.read_vu128()), Ok(u128::MAX));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn truncated() {
+        let buf = fastvlq::Vu64::new(u64::MAX);
+        let buf = buf.as_slice();
+        for len in 0..buf.len() {
+            assert_eq!(
+                ready((&buf[..len]).read_vu64()),
+                Err(ReadError::Decode(DecodeError::UnexpectedEof))
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Check that values an encoding cannot hold fail with `EncodeError`.
+
+use fastvlq::quic::{QuicVarInt, WriteQuicExt, encode_quic_varint};
+use fastvlq::{EncodeError, FixedBeN, IntCodec, WriteVlqExt};
+
+#[test]
+fn quic_above_max() {
+    assert_eq!(
+        encode_quic_varint(QuicVarInt::MAX).unwrap().get(),
+        QuicVarInt::MAX
+    );
+    assert_eq!(
+        encode_quic_varint(QuicVarInt::MAX + 1).unwrap_err(),
+        EncodeError::Overflow
+    );
+    assert_eq!(
+        QuicVarInt::try_from(fastvlq::Vu64::new(u64::MAX)).unwrap_err(),
+        EncodeError::Overflow
+    );
+
+    let err = Vec::new().write_quic_varint(u64::MAX).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        *err.get_ref()
+            .unwrap()
+            .downcast_ref::<EncodeError>()
+            .unwrap(),
+        EncodeError::Overflow
+    );
+}
+
+#[test]
+fn codec_too_narrow() {
+    let mut buf = [0u8; 2];
+    assert_eq!(FixedBeN::<2>::encode_u64(0xffff, &mut buf), Ok(2));
+    assert_eq!(
+        FixedBeN::<2>::encode_u64(0x1_0000, &mut buf),
+        Err(EncodeError::Overflow)
+    );
+    let err = Vec::new()
+        .write_u64_with::<FixedBeN<2>>(0x1_0000)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,109 @@
+//! Check that `Vf32` and `Vf64` keep every bit of their input.
+
+use fastvlq::{ReadVlqExt, Vf32, Vf64, WriteVlqExt};
+use proptest::prelude::*;
+
+fn round_trip_f64(n: f64) -> Vf64 {
+    let vlq = Vf64::new(n);
+    assert_eq!(vlq.get().to_bits(), n.to_bits(), "{n:?}");
+    assert_eq!(vlq.len(), Vf64::encoded_len(n));
+    let mut buf = Vec::new();
+    buf.write_vf64(n).unwrap();
+    assert_eq!(buf, vlq.as_slice());
+    assert_eq!((&buf[..]).read_vf64().unwrap().to_bits(), n.to_bits());
+    vlq
+}
+
+fn round_trip_f32(n: f32) -> Vf32 {
+    let vlq = Vf32::new(n);
+    assert_eq!(vlq.get().to_bits(), n.to_bits(), "{n:?}");
+    assert_eq!(vlq.len(), Vf32::encoded_len(n));
+    let mut buf = Vec::new();
+    buf.write_vf32(n).unwrap();
+    assert_eq!(buf, vlq.as_slice());
+    assert_eq!((&buf[..]).read_vf32().unwrap().to_bits(), n.to_bits());
+    vlq
+}
+
+#[test]
+fn negative_zero() {
+    let negative = round_trip_f64(-0.0);
+    assert!(negative.get().is_sign_negative());
+    assert_ne!(negative.as_slice(), Vf64::new(0.0).as_slice());
+
+    let negative = round_trip_f32(-0.0);
+    assert!(negative.get().is_sign_negative());
+    assert_ne!(negative.as_slice(), Vf32::new(0.0).as_slice());
+}
+
+#[test]
+fn nan_payloads() {
+    // Signalling NaNs have the quiet bit clear and a non-zero payload
+    for bits in [
+        0x7ff0_0000_0000_0001,
+        0xfff0_0000_0000_0001,
+        0x7ff4_0000_0000_0000,
+        0x7ff8_0000_0000_0000,
+        0x7fff_ffff_ffff_ffff,
+    ] {
+        assert!(round_trip_f64(f64::from_bits(bits)).get().is_nan());
+    }
+    for bits in [
+        0x7f80_0001,
+        0xff80_0001,
+        0x7fa0_0000,
+        0x7fc0_0000,
+        0x7fff_ffff,
+    ] {
+        assert!(round_trip_f32(f32::from_bits(bits)).get().is_nan());
+    }
+}
+
+#[test]
+fn short_values() {
+    for n in [1.0, 0.5, -2.0] {
+        assert_eq!(round_trip_f64(n).len(), 1, "{n}");
+        assert_eq!(round_trip_f32(n as f32).len(), 1, "{n}");
+    }
+    assert_eq!(round_trip_f64(100.0).len(), 3);
+    // The narrower exponent code leaves room for more of the mantissa
+    assert_eq!(round_trip_f32(100.0).len(), 2);
+}
+
+#[test]
+fn specials() {
+    for n in [
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::MIN,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        f64::from_bits(1),
+    ] {
+        round_trip_f64(n);
+    }
+    for n in [
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::MIN,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::EPSILON,
+        f32::from_bits(1),
+    ] {
+        round_trip_f32(n);
+    }
+}
+
+proptest! {
+    #[test]
+    fn f64_bits_round_trip(bits: u64) {
+        round_trip_f64(f64::from_bits(bits));
+    }
+
+    #[test]
+    fn f32_bits_round_trip(bits: u32) {
+        round_trip_f32(f32::from_bits(bits));
+    }
+}
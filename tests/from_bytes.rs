@@ -0,0 +1,141 @@
+//! Check the slice and array constructors against the encoders.
+
+use fastvlq::{DecodeError, Vi32, Vi64, Vi128, Vu32, Vu64, Vu128};
+
+macro_rules! round_trips {
+    ($name:ident, $ty:ident, $values:expr) => {
+        #[test]
+        fn $name() {
+            for n in $values {
+                let v = $ty::new(n);
+                let len = v.len() as usize;
+
+                let mut buf = v.as_slice().to_vec();
+                assert_eq!($ty::from_bytes(&buf).unwrap(), (v, len), "{n}");
+                // Anything after the encoding is left alone
+                buf.extend_from_slice(&[0xff, 0x00]);
+                assert_eq!($ty::from_bytes(&buf).unwrap(), (v, len), "{n}");
+                for end in 0..len {
+                    assert_eq!(
+                        $ty::from_bytes(&buf[..end]).unwrap_err(),
+                        DecodeError::UnexpectedEof,
+                        "{n} cut to {end} bytes"
+                    );
+                }
+
+                assert_eq!($ty::from_array(v.bytes()).unwrap(), v, "{n}");
+                // SAFETY: the bytes come from `new`
+                assert_eq!(unsafe { $ty::from_array_unchecked(v.bytes()) }, v, "{n}");
+
+                // Every byte past the encoding must stay zero
+                for i in len..v.bytes().len() {
+                    let mut bytes = v.bytes();
+                    bytes[i] = 1;
+                    assert_eq!(
+                        $ty::from_array(bytes).unwrap_err(),
+                        DecodeError::NonCanonical,
+                        "{n} with byte {i} set"
+                    );
+                }
+            }
+        }
+    };
+}
+
+round_trips!(
+    vu32,
+    Vu32,
+    [0, 1, 0x7f, 0x80, 0x407f, 0x4080, u32::MAX - 1, u32::MAX]
+);
+round_trips!(vi32, Vi32, [0, -1, 1, -64, 64, i32::MIN, i32::MAX]);
+round_trips!(vu64, Vu64, [0, 0x7f, 0x80, 1 << 56, u64::MAX - 1, u64::MAX]);
+round_trips!(vi64, Vi64, [0, -1, 1, -64, 64, i64::MIN, i64::MAX]);
+round_trips!(vu128, Vu128, [0, 0x80, 1 << 64, 1 << 100, u128::MAX]);
+round_trips!(
+    vi128,
+    Vi128,
+    [0, -1, 1, -64, 64, i64::MIN as i128, i128::MIN, i128::MAX]
+);
+
+#[test]
+fn empty() {
+    assert_eq!(
+        Vu32::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vi32::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vu64::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vi64::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vi128::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+}
+
+#[test]
+fn overflow() {
+    // A first byte of zero starts the 9-byte form, which `Vu32` has no room for
+    let too_long = [0u8; 5];
+    assert_eq!(
+        Vu32::from_array(too_long).unwrap_err(),
+        DecodeError::Overflow
+    );
+    assert_eq!(
+        Vi32::from_array(too_long).unwrap_err(),
+        DecodeError::Overflow
+    );
+    assert_eq!(
+        Vu32::from_bytes(&[0; 9]).unwrap_err(),
+        DecodeError::Overflow
+    );
+
+    // The largest 5-byte payload is past `u32::MAX` once the offset is added
+    let mut past_max = Vu32::new(u32::MAX).bytes();
+    past_max[1..].fill(0xff);
+    assert_eq!(
+        Vu32::from_array(past_max).unwrap_err(),
+        DecodeError::Overflow
+    );
+    assert_eq!(
+        Vi32::from_bytes(&past_max).unwrap_err(),
+        DecodeError::Overflow
+    );
+
+    let mut past_max = Vu64::new(u64::MAX).bytes();
+    past_max[1..].fill(0xff);
+    assert_eq!(
+        Vu64::from_array(past_max).unwrap_err(),
+        DecodeError::Overflow
+    );
+    assert_eq!(
+        Vi64::from_bytes(&past_max).unwrap_err(),
+        DecodeError::Overflow
+    );
+}
+
+#[test]
+fn raw_form_of_a_short_value() {
+    // The raw 18-byte form of zero, which has a 1-byte encoding
+    let zero = [0u8; 18];
+    assert_eq!(
+        Vu128::from_array(zero).unwrap_err(),
+        DecodeError::NonCanonical
+    );
+    assert_eq!(
+        Vi128::from_array(zero).unwrap_err(),
+        DecodeError::NonCanonical
+    );
+    assert_eq!(
+        Vi128::from_bytes(&zero).unwrap_err(),
+        DecodeError::NonCanonical
+    );
+}
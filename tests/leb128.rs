@@ -0,0 +1,239 @@
+//! Round-trip LEB128 and check the overflow rules and stream transcoders.
+
+use fastvlq::leb128::{
+    ReadLeb128Ext, WriteLeb128Ext, decode_sleb128, decode_uleb128, encode_sleb128, encode_uleb128,
+    leb128_to_vu64, vu64_to_leb128,
+};
+use fastvlq::{DecodeError, ReadVlqExt, WriteVlqExt};
+use proptest::prelude::*;
+
+fn decode_error(err: &std::io::Error) -> DecodeError {
+    *err.get_ref()
+        .and_then(|inner| inner.downcast_ref::<DecodeError>())
+        .unwrap()
+}
+
+/// Nine continuation bytes holding `low`, followed by `last`.
+fn ten_bytes(low: u8, last: u8) -> [u8; 10] {
+    let mut buf = [low | 0x80; 10];
+    buf[9] = last;
+    buf
+}
+
+fn round_trip_u(n: u64) {
+    let enc = encode_uleb128(n);
+    assert_eq!(decode_uleb128(enc.as_slice()), Ok((n, enc.len() as usize)));
+    let mut buf = Vec::new();
+    buf.write_uleb128(n).unwrap();
+    assert_eq!(buf, enc.as_slice());
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_uleb128().unwrap(), n);
+    assert!(reader.is_empty());
+}
+
+fn round_trip_s(n: i64) {
+    let enc = encode_sleb128(n);
+    assert_eq!(decode_sleb128(enc.as_slice()), Ok((n, enc.len() as usize)));
+    let mut buf = Vec::new();
+    buf.write_sleb128(n).unwrap();
+    assert_eq!(buf, enc.as_slice());
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_sleb128().unwrap(), n);
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn known_encodings() {
+    assert_eq!(encode_uleb128(0).as_slice(), [0x00]);
+    assert_eq!(encode_uleb128(624_485).as_slice(), [0xe5, 0x8e, 0x26]);
+    assert_eq!(encode_uleb128(u64::MAX).as_slice(), ten_bytes(0x7f, 0x01));
+    assert_eq!(encode_sleb128(-1).as_slice(), [0x7f]);
+    assert_eq!(encode_sleb128(64).as_slice(), [0xc0, 0x00]);
+    assert_eq!(encode_sleb128(-123_456).as_slice(), [0xc0, 0xbb, 0x78]);
+    assert_eq!(encode_sleb128(i64::MIN).as_slice(), ten_bytes(0x00, 0x7f));
+    assert_eq!(encode_sleb128(i64::MAX).as_slice(), ten_bytes(0x7f, 0x00));
+}
+
+#[test]
+fn padding_is_accepted() {
+    assert_eq!(decode_uleb128(&[0x80, 0x00]), Ok((0, 2)));
+    assert_eq!(decode_uleb128(&ten_bytes(0x00, 0x00)), Ok((0, 10)));
+    assert_eq!(decode_sleb128(&[0xff, 0x7f]), Ok((-1, 2)));
+    assert_eq!(decode_sleb128(&ten_bytes(0x7f, 0x7f)), Ok((-1, 10)));
+}
+
+#[test]
+fn uleb128_tenth_byte() {
+    // The tenth byte holds bit 63 alone and cannot continue
+    assert_eq!(decode_uleb128(&ten_bytes(0x00, 0x01)), Ok((1 << 63, 10)));
+    for last in [0x02, 0x7f, 0x80, 0x81, 0xff] {
+        let buf = ten_bytes(0x7f, last);
+        assert_eq!(
+            decode_uleb128(&buf),
+            Err(DecodeError::Overflow),
+            "{last:#x}"
+        );
+        let err = (&buf[..]).read_uleb128().unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+    }
+}
+
+#[test]
+fn sleb128_tenth_byte() {
+    // The tenth byte holds bit 63 sign-extended, so only all zeros or all ones
+    assert_eq!(decode_sleb128(&ten_bytes(0x7f, 0x00)), Ok((i64::MAX, 10)));
+    assert_eq!(decode_sleb128(&ten_bytes(0x00, 0x7f)), Ok((i64::MIN, 10)));
+    for last in [0x01, 0x3f, 0x40, 0x7e, 0x80, 0xff] {
+        let buf = ten_bytes(0x00, last);
+        assert_eq!(
+            decode_sleb128(&buf),
+            Err(DecodeError::Overflow),
+            "{last:#x}"
+        );
+        let err = (&buf[..]).read_sleb128().unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+    }
+}
+
+#[test]
+fn reader_stops_after_ten_bytes() {
+    // An eleventh continuation byte is never read
+    let buf = [0x80u8; 11];
+    let mut reader = &buf[..];
+    let err = reader.read_uleb128().unwrap_err();
+    assert_eq!(decode_error(&err), DecodeError::Overflow);
+    assert_eq!(reader.len(), 1);
+}
+
+#[test]
+fn truncated() {
+    for buf in [&[][..], &[0x80], &ten_bytes(0x7f, 0x01)[..9]] {
+        assert_eq!(decode_uleb128(buf), Err(DecodeError::UnexpectedEof));
+        assert_eq!(decode_sleb128(buf), Err(DecodeError::UnexpectedEof));
+        let err = (&buf[..]).read_uleb128().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        let err = (&buf[..]).read_sleb128().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
+
+const STREAM: [u64; 7] = [0, 1, 0x7f, 0x80, 1 << 35, u64::MAX - 1, u64::MAX];
+
+#[test]
+fn transcode_round_trip() {
+    let mut leb = Vec::new();
+    let mut vlq = Vec::new();
+    for n in STREAM {
+        leb.write_uleb128(n).unwrap();
+        vlq.write_vu64(n).unwrap();
+    }
+
+    let mut out = Vec::new();
+    assert_eq!(
+        leb128_to_vu64(&leb[..], &mut out).unwrap(),
+        STREAM.len() as u64
+    );
+    assert_eq!(out, vlq);
+
+    let mut out = Vec::new();
+    assert_eq!(
+        vu64_to_leb128(&vlq[..], &mut out).unwrap(),
+        STREAM.len() as u64
+    );
+    assert_eq!(out, leb);
+
+    let mut reader = &out[..];
+    for n in STREAM {
+        assert_eq!(reader.read_uleb128().unwrap(), n);
+    }
+
+    assert_eq!(leb128_to_vu64(&[][..], Vec::new()).unwrap(), 0);
+    assert_eq!(vu64_to_leb128(&[][..], Vec::new()).unwrap(), 0);
+}
+
+#[test]
+fn transcode_truncated() {
+    let mut leb = Vec::new();
+    leb.write_uleb128(1).unwrap();
+    leb.write_uleb128(u64::MAX).unwrap();
+    let mut out = Vec::new();
+    let err = leb128_to_vu64(&leb[..leb.len() - 1], &mut out).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    // Values before the cut are written
+    assert_eq!((&out[..]).read_vu64().unwrap(), 1);
+
+    let mut vlq = Vec::new();
+    vlq.write_vu64(1).unwrap();
+    vlq.write_vu64(u64::MAX).unwrap();
+    let mut out = Vec::new();
+    let err = vu64_to_leb128(&vlq[..vlq.len() - 1], &mut out).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    assert_eq!(out, [0x01]);
+}
+
+#[test]
+fn transcode_overflow() {
+    let leb = ten_bytes(0x7f, 0x02);
+    let err = leb128_to_vu64(&leb[..], Vec::new()).unwrap_err();
+    assert_eq!(decode_error(&err), DecodeError::Overflow);
+}
+
+proptest! {
+    #[test]
+    fn round_trips(bits in 0u32..64, n: u64) {
+        round_trip_u(n >> bits);
+        round_trip_s((n >> bits) as i64);
+        round_trip_s(n as i64 >> bits);
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use fastvlq::DecodeError;
+    use fastvlq::leb128::{
+        AsyncReadLeb128Ext, AsyncWriteLeb128Ext, encode_sleb128, encode_uleb128,
+    };
+
+    use super::{decode_error, ten_bytes};
+
+    /// Run a future that never waits, as reads from slices and writes to vectors don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    #[test]
+    fn matches_sync() {
+        for n in [0, 0x7f, 0x80, 1 << 40, u64::MAX] {
+            let mut buf = Vec::new();
+            ready(AsyncWriteLeb128Ext::write_uleb128(&mut buf, n)).unwrap();
+            assert_eq!(buf, encode_uleb128(n).as_slice());
+            assert_eq!(ready((&mut &buf[..]).read_uleb128()).unwrap(), n);
+        }
+        for n in [0, -1, 64, -65, i64::MIN, i64::MAX] {
+            let mut buf = Vec::new();
+            ready(AsyncWriteLeb128Ext::write_sleb128(&mut buf, n)).unwrap();
+            assert_eq!(buf, encode_sleb128(n).as_slice());
+            assert_eq!(ready((&mut &buf[..]).read_sleb128()).unwrap(), n);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let buf = ten_bytes(0x7f, 0x02);
+        let err = ready((&mut &buf[..]).read_uleb128()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+        let err = ready((&mut &buf[..]).read_sleb128()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::Overflow);
+
+        let err = ready((&mut &buf[..9]).read_uleb128()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
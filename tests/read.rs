@@ -0,0 +1,125 @@
+//! Check the `Read` and `BufRead` extensions against the encoders.
+
+use std::io::{BufReader, Read};
+
+use fastvlq::{BufReadVlqExt, ReadVlqExt, Vu32, Vu64, Vu128, WriteVlqExt};
+
+/// `2^k - 1` and `2^k` for every `k` below `bits`, which lands on both sides of every
+/// length class boundary.
+fn boundaries(bits: u32) -> Vec<u128> {
+    let mut values = vec![0];
+    for k in 1..bits {
+        values.push((1u128 << k) - 1);
+        values.push(1u128 << k);
+    }
+    values.push(u128::MAX >> (128 - bits));
+    values
+}
+
+#[test]
+fn read_vu128_every_length() {
+    let mut lens = Vec::new();
+    for n in boundaries(128) {
+        let encoded = Vu128::new(n);
+        lens.push(encoded.len());
+        // Trailing byte catches reads that stop early or run over
+        let mut buf = encoded.as_slice().to_vec();
+        buf.push(0xa5);
+        let mut reader = &buf[..];
+        assert_eq!(reader.read_vu128().unwrap(), n, "{n:#x}");
+        assert_eq!(reader, [0xa5], "{n:#x}");
+    }
+    lens.sort();
+    lens.dedup();
+    // Nothing encodes to 17 bytes; the raw 18-byte form takes over past the 16-byte class
+    assert_eq!(lens, (1..=16).chain([18]).collect::<Vec<_>>());
+}
+
+#[test]
+fn read_vu128_multi_byte_standard() {
+    // Regression: the second byte of standard 3 to 8 byte encodings was skipped
+    for len in 3..=8 {
+        let n = boundaries(128)
+            .into_iter()
+            .filter(|&n| Vu128::encoded_len(n) == len)
+            .max()
+            .unwrap();
+        assert_ne!(Vu128::new(n).as_slice()[1], 0);
+        let mut buf = Vec::new();
+        buf.write_vu128(n).unwrap();
+        buf.write_vu128(n).unwrap();
+        let mut reader = &buf[..];
+        assert_eq!(reader.read_vu128().unwrap(), n);
+        assert_eq!(reader.read_vu128().unwrap(), n);
+        assert!(reader.is_empty());
+    }
+}
+
+#[test]
+fn read_vi128_every_length() {
+    for n in boundaries(127) {
+        for n in [n as i128, -(n as i128) - 1] {
+            let mut buf = Vec::new();
+            buf.write_vi128(n).unwrap();
+            assert_eq!((&buf[..]).read_vi128().unwrap(), n);
+        }
+    }
+}
+
+/// Everything `boundaries` gives for each type, written back to back.
+fn stream() -> (Vec<u8>, Vec<u128>) {
+    let mut buf = Vec::new();
+    let values = boundaries(128);
+    for &n in &values {
+        buf.write_vu32(n as u32).unwrap();
+        buf.write_vi32(n as i32).unwrap();
+        buf.write_vu64(n as u64).unwrap();
+        buf.write_vi64(n as i64).unwrap();
+        buf.write_vu128(n).unwrap();
+        buf.write_vi128(n as i128).unwrap();
+    }
+    (buf, values)
+}
+
+#[test]
+fn buffered_reads_across_fill_buf() {
+    let (buf, values) = stream();
+    // Capacities below the longest encoding split values across `fill_buf` calls
+    for capacity in 1..=20 {
+        let mut reader = BufReader::with_capacity(capacity, &buf[..]);
+        for &n in &values {
+            assert_eq!(reader.read_vu32_buffered().unwrap(), n as u32);
+            assert_eq!(reader.read_vi32_buffered().unwrap(), n as i32);
+            assert_eq!(reader.read_vu64_buffered().unwrap(), n as u64);
+            assert_eq!(reader.read_vi64_buffered().unwrap(), n as i64);
+            assert_eq!(reader.read_vu128_buffered().unwrap(), n, "{capacity}");
+            assert_eq!(reader.read_vi128_buffered().unwrap(), n as i128);
+        }
+        assert_eq!(reader.read(&mut [0]).unwrap(), 0);
+    }
+}
+
+#[test]
+fn buffered_reads_match_unbuffered() {
+    let mut buf = Vec::new();
+    for n in boundaries(32) {
+        buf.extend_from_slice(Vu32::new(n as u32).as_slice());
+    }
+    for n in boundaries(64) {
+        buf.extend_from_slice(Vu64::new(n as u64).as_slice());
+    }
+    let mut unbuffered = &buf[..];
+    let mut buffered = BufReader::with_capacity(1, &buf[..]);
+    for _ in boundaries(32) {
+        assert_eq!(
+            buffered.read_vu32_buffered().unwrap(),
+            unbuffered.read_vu32().unwrap()
+        );
+    }
+    for _ in boundaries(64) {
+        assert_eq!(
+            buffered.read_vu64_buffered().unwrap(),
+            unbuffered.read_vu64().unwrap()
+        );
+    }
+}
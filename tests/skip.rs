@@ -0,0 +1,170 @@
+//! Check that skipping values lands where decoding them does.
+
+use fastvlq::{
+    DecodeError, ReadVlqExt, Vi32, Vi64, Vi128, Vu32, Vu64, Vu128, WriteVlqExt, skip_vu64,
+    skip_vu128,
+};
+use proptest::prelude::*;
+
+/// Encode `values` as a mix of the 32- and 64-bit types, which all skip as `Vu64`.
+fn encode_64(values: &[u64]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for (i, &n) in values.iter().enumerate() {
+        match i % 4 {
+            0 => buf.extend_from_slice(Vu64::new(n).as_slice()),
+            1 => buf.extend_from_slice(Vi64::new(n as i64).as_slice()),
+            2 => buf.extend_from_slice(Vu32::new(n as u32).as_slice()),
+            _ => buf.extend_from_slice(Vi32::new(n as i32).as_slice()),
+        }
+    }
+    buf
+}
+
+fn encode_128(values: &[u128]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for (i, &n) in values.iter().enumerate() {
+        if i % 2 == 0 {
+            buf.write_vu128(n).unwrap();
+        } else {
+            buf.write_vi128(n as i128).unwrap();
+        }
+    }
+    buf
+}
+
+/// Offsets just past each value, found by decoding.
+fn decoded_ends_64(values: &[u64], buf: &[u8]) -> Vec<usize> {
+    let mut reader = buf;
+    values
+        .iter()
+        .map(|_| {
+            reader.read_vu64().unwrap();
+            buf.len() - reader.len()
+        })
+        .collect()
+}
+
+fn decoded_ends_128(values: &[u128], buf: &[u8]) -> Vec<usize> {
+    let mut reader = buf;
+    values
+        .iter()
+        .map(|_| {
+            reader.read_vu128().unwrap();
+            buf.len() - reader.len()
+        })
+        .collect()
+}
+
+fn check_64(values: &[u64]) {
+    let buf = encode_64(values);
+    let ends = decoded_ends_64(values, &buf);
+    assert_eq!(skip_vu64(&buf, 0), Ok(0));
+    for (count, &end) in (1..).zip(&ends) {
+        assert_eq!(skip_vu64(&buf, count), Ok(end));
+        let mut reader = &buf[..];
+        assert_eq!(reader.skip_n_vu64(count).unwrap(), end);
+        assert_eq!(reader.len(), buf.len() - end);
+    }
+    let mut reader = &buf[..];
+    for (i, &end) in ends.iter().enumerate() {
+        let start = if i == 0 { 0 } else { ends[i - 1] };
+        assert_eq!(reader.skip_vu64().unwrap(), end - start);
+    }
+
+    // Every cut inside the last value is caught
+    if let Some(&last) = ends.last() {
+        let start = ends.len().checked_sub(2).map_or(0, |i| ends[i]);
+        for cut in start..last {
+            assert_eq!(
+                skip_vu64(&buf[..cut], values.len()),
+                Err(DecodeError::UnexpectedEof)
+            );
+            let err = (&buf[..cut]).skip_n_vu64(values.len()).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        }
+    }
+}
+
+fn check_128(values: &[u128]) {
+    let buf = encode_128(values);
+    let ends = decoded_ends_128(values, &buf);
+    for (count, &end) in (1..).zip(&ends) {
+        assert_eq!(skip_vu128(&buf, count), Ok(end));
+        let mut reader = &buf[..];
+        assert_eq!(reader.skip_n_vu128(count).unwrap(), end);
+        assert_eq!(reader.len(), buf.len() - end);
+    }
+
+    if let Some(&last) = ends.last() {
+        let start = ends.len().checked_sub(2).map_or(0, |i| ends[i]);
+        for cut in start..last {
+            assert_eq!(
+                skip_vu128(&buf[..cut], values.len()),
+                Err(DecodeError::UnexpectedEof)
+            );
+            let err = (&buf[..cut]).skip_n_vu128(values.len()).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        }
+    }
+}
+
+#[test]
+fn every_length() {
+    let values: Vec<u64> = (0..64).map(|bits| 1 << bits).chain([0, u64::MAX]).collect();
+    check_64(&values);
+    let values: Vec<u128> = (0..128)
+        .map(|bits| 1 << bits)
+        .chain([0, u128::MAX])
+        .collect();
+    check_128(&values);
+    // Even positions are written as `Vu128` and odd ones as `Vi128`, taking in the raw
+    // 18-byte form and the extended classes
+    let nine_byte_min: u128 = (1..9).map(|k| 1 << (7 * k)).sum::<u128>() + (1 << 63);
+    let values = [
+        nine_byte_min - 1,
+        i128::MIN as u128,
+        nine_byte_min,
+        -1i128 as u128,
+        1 << 70,
+        1 << 100,
+    ];
+    assert_eq!(Vu128::encoded_len(nine_byte_min - 1), 18);
+    assert_eq!(Vi128::encoded_len(i128::MIN), 18);
+    assert_eq!(Vu128::encoded_len(nine_byte_min), 9);
+    assert_eq!(Vu128::encoded_len(1 << 70), 10);
+    check_128(&values);
+}
+
+#[test]
+fn past_the_end() {
+    let buf = encode_64(&[1, 2, 3]);
+    assert_eq!(skip_vu64(&buf, 3), Ok(buf.len()));
+    assert_eq!(skip_vu64(&buf, 4), Err(DecodeError::UnexpectedEof));
+    assert_eq!(skip_vu64(&[], 1), Err(DecodeError::UnexpectedEof));
+    assert_eq!(skip_vu128(&[], 1), Err(DecodeError::UnexpectedEof));
+    // A lone `0x00` needs its second byte to know the length
+    assert_eq!(skip_vu128(&[0x00], 1), Err(DecodeError::UnexpectedEof));
+    assert_eq!(skip_vu128(&[0x00], 0), Ok(0));
+    let err = (&[0x00][..]).skip_vu128().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+fn spread_64() -> impl Strategy<Value = u64> {
+    (0u32..64, any::<u64>()).prop_map(|(bits, n)| n >> bits)
+}
+
+fn spread_128() -> impl Strategy<Value = u128> {
+    (0u32..128, any::<u128>()).prop_map(|(bits, n)| n >> bits)
+}
+
+proptest! {
+    #[test]
+    fn skips_match_decodes_64(values in prop::collection::vec(spread_64(), 0..20)) {
+        check_64(&values);
+    }
+
+    #[test]
+    fn skips_match_decodes_128(values in prop::collection::vec(spread_128(), 0..20)) {
+        check_128(&values);
+    }
+}
@@ -0,0 +1,75 @@
+//! Check SQLite4 varints against the class boundaries of the format.
+
+use fastvlq::DecodeError;
+use fastvlq::sqlite4::{ReadSqlite4Ext, Sqlite4Varint, WriteSqlite4Ext, decode_sqlite4};
+
+/// The first and last value of every length class.
+const BOUNDARIES: &[(u64, &[u8])] = &[
+    (0, &[0x00]),
+    (240, &[0xf0]),
+    (241, &[0xf1, 0x01]),
+    (2287, &[0xf8, 0xff]),
+    (2288, &[0xf9, 0x00, 0x00]),
+    (67823, &[0xf9, 0xff, 0xff]),
+    (67824, &[0xfa, 0x01, 0x08, 0xf0]),
+    ((1 << 24) - 1, &[0xfa, 0xff, 0xff, 0xff]),
+    (1 << 24, &[0xfb, 0x01, 0x00, 0x00, 0x00]),
+    ((1 << 32) - 1, &[0xfb, 0xff, 0xff, 0xff, 0xff]),
+    (1 << 32, &[0xfc, 0x01, 0x00, 0x00, 0x00, 0x00]),
+    ((1 << 40) - 1, &[0xfc, 0xff, 0xff, 0xff, 0xff, 0xff]),
+    (1 << 40, &[0xfd, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    ((1 << 48) - 1, &[0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+    (1 << 48, &[0xfe, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    (
+        (1 << 56) - 1,
+        &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    ),
+    (
+        1 << 56,
+        &[0xff, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    ),
+    (
+        u64::MAX,
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    ),
+];
+
+#[test]
+fn class_boundaries() {
+    for &(value, encoding) in BOUNDARIES {
+        let vlq = Sqlite4Varint::new(value);
+        assert_eq!(vlq.as_slice(), encoding, "{value}");
+        assert_eq!(vlq.len() as usize, encoding.len());
+        assert_eq!(Sqlite4Varint::encoded_len(value) as usize, encoding.len());
+        assert_eq!(
+            Sqlite4Varint::len_from_first_byte(encoding[0]) as usize,
+            encoding.len()
+        );
+        assert_eq!(decode_sqlite4(encoding), Ok((value, encoding.len())));
+
+        let mut buf = Vec::new();
+        buf.write_sqlite4(value).unwrap();
+        assert_eq!(buf, encoding);
+        assert_eq!((&buf[..]).read_sqlite4().unwrap(), value);
+    }
+}
+
+#[test]
+fn wider_forms_decode() {
+    // SQLite4 has no canonical-form rule, so longer forms decode like any other
+    assert_eq!(decode_sqlite4(&[0xf1, 0x00]), Ok((240, 2)));
+    assert_eq!(decode_sqlite4(&[0xf9, 0x00, 0x05]), Ok((2293, 3)));
+    assert_eq!(decode_sqlite4(&[0xfa, 0x00, 0x00, 0x05]), Ok((5, 4)));
+}
+
+#[test]
+fn truncated() {
+    for &(_, encoding) in BOUNDARIES {
+        for len in 0..encoding.len() {
+            assert_eq!(
+                decode_sqlite4(&encoding[..len]),
+                Err(DecodeError::UnexpectedEof)
+            );
+        }
+    }
+}
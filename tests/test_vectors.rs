@@ -0,0 +1,132 @@
+//! Check this implementation against its own golden test vectors.
+
+use std::io::ErrorKind;
+
+use fastvlq::test_vectors::*;
+use fastvlq::{DecodeError, ReadVlqExt, VlqDecode, VlqEncode, Vu32, Vu64, Vu128, Vu128V2};
+
+fn check<T: VlqEncode + VlqDecode + PartialEq + std::fmt::Debug + Copy>(vectors: &[Vector<T>]) {
+    for v in vectors {
+        assert_eq!(v.value.encode_vlq().unwrap(), v.encoding, "{:?}", v.value);
+        assert_eq!(
+            T::decode_vlq(v.encoding).unwrap(),
+            (v.value, v.encoding.len())
+        );
+    }
+}
+
+fn check_non_canonical<T: VlqEncode + VlqDecode + PartialEq + std::fmt::Debug + Copy>(
+    vectors: &[NonCanonicalVector<T>],
+) {
+    for v in vectors {
+        assert_eq!(
+            T::decode_vlq(v.encoding).unwrap(),
+            (v.value, v.encoding.len())
+        );
+        assert_eq!(v.value.encode_vlq().unwrap(), v.canonical);
+    }
+}
+
+fn check_invalid<T: VlqDecode + std::fmt::Debug>(vectors: &[InvalidVector]) {
+    for v in vectors {
+        assert_eq!(
+            T::decode_vlq(v.encoding).unwrap_err(),
+            v.error,
+            "{:x?}",
+            v.encoding
+        );
+    }
+}
+
+#[test]
+fn vu32() {
+    check(VU32);
+    check_invalid::<u32>(VU32_INVALID);
+    for v in VU32_INVALID {
+        assert_eq!(Vu32::from_bytes(v.encoding).unwrap_err(), v.error);
+    }
+}
+
+#[test]
+fn vi32() {
+    check(VI32);
+    check_invalid::<i32>(VU32_INVALID);
+}
+
+#[test]
+fn vu64() {
+    check(VU64);
+    check_invalid::<u64>(VU64_INVALID);
+    for v in VU64_INVALID {
+        assert_eq!(Vu64::from_bytes(v.encoding).unwrap_err(), v.error);
+    }
+}
+
+#[test]
+fn vi64() {
+    check(VI64);
+    check_invalid::<i64>(VU64_INVALID);
+}
+
+#[test]
+fn vu128() {
+    check(VU128);
+    check_non_canonical(VU128_NON_CANONICAL);
+    check_invalid::<u128>(VU128_INVALID);
+    for v in VU128_NON_CANONICAL {
+        assert_eq!(
+            Vu128::from_bytes(v.encoding).unwrap_err(),
+            DecodeError::NonCanonical
+        );
+    }
+    for v in VU128_INVALID {
+        assert_eq!(Vu128::from_bytes(v.encoding).unwrap_err(), v.error);
+    }
+}
+
+#[test]
+fn vi128() {
+    check(VI128);
+    check_invalid::<i128>(VU128_INVALID);
+}
+
+#[test]
+fn vu128_v2() {
+    for v in VU128_V2 {
+        assert_eq!(Vu128V2::new(v.value).as_slice(), v.encoding);
+        let mut reader = v.encoding;
+        assert_eq!(reader.read_vu128_v2().unwrap(), v.value);
+        assert!(reader.is_empty());
+    }
+    for v in VU128_V2_INVALID {
+        let e = (&mut { v.encoding }).read_vu128_v2().unwrap_err();
+        let error = match e.kind() {
+            ErrorKind::UnexpectedEof => DecodeError::UnexpectedEof,
+            _ => *e.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap(),
+        };
+        assert_eq!(error, v.error, "{:x?}", v.encoding);
+    }
+}
+
+#[test]
+fn floats() {
+    // Compare bits so that NaN matches itself
+    for v in VF32 {
+        assert_eq!(v.value.encode_vlq().unwrap(), v.encoding);
+        let (value, len) = f32::decode_vlq(v.encoding).unwrap();
+        assert_eq!(
+            (value.to_bits(), len),
+            (v.value.to_bits(), v.encoding.len())
+        );
+    }
+    for v in VF64 {
+        assert_eq!(v.value.encode_vlq().unwrap(), v.encoding);
+        let (value, len) = f64::decode_vlq(v.encoding).unwrap();
+        assert_eq!(
+            (value.to_bits(), len),
+            (v.value.to_bits(), v.encoding.len())
+        );
+    }
+    check_invalid::<f32>(VU32_INVALID);
+    check_invalid::<f64>(VU64_INVALID);
+}
@@ -0,0 +1,182 @@
+//! Round-trip durations and timestamps, and check the nanoseconds are validated.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use fastvlq::{
+    DecodeError, ReadVlqExt, VDuration, Vi64, Vu32, Vu64, WriteVlqExt, decode_duration,
+    encode_duration,
+};
+
+fn decode_error(err: &std::io::Error) -> DecodeError {
+    *err.get_ref()
+        .and_then(|inner| inner.downcast_ref::<DecodeError>())
+        .unwrap()
+}
+
+/// Encode seconds and nanoseconds without going through `Duration`, so the
+/// nanoseconds can be out of range.
+fn raw(secs: &[u8], nanos: u32) -> Vec<u8> {
+    let mut buf = secs.to_vec();
+    buf.extend_from_slice(Vu32::new(nanos).as_slice());
+    buf
+}
+
+#[test]
+fn duration_round_trips() {
+    for d in [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::new(1, 999_999_999),
+        Duration::new(1 << 40, 123_456_789),
+        Duration::MAX,
+    ] {
+        let encoded = encode_duration(d);
+        assert_eq!(encoded.get(), d);
+        let mut buf = encoded.as_slice().to_vec();
+        assert_eq!(decode_duration(&buf), Ok((d, buf.len())));
+
+        let mut written = Vec::new();
+        written.write_duration(d).unwrap();
+        assert_eq!(written, buf);
+        let mut reader = &written[..];
+        assert_eq!(reader.read_duration().unwrap(), d);
+        assert!(reader.is_empty());
+
+        buf.push(0xff);
+        assert_eq!(decode_duration(&buf), Ok((d, buf.len() - 1)));
+    }
+    assert_eq!(
+        encode_duration(Duration::MAX).as_slice().len(),
+        VDuration::MAX_LEN
+    );
+}
+
+#[test]
+fn duration_layout() {
+    let d = Duration::new(300, 5);
+    let expected = raw(Vu64::new(300).as_slice(), 5);
+    assert_eq!(encode_duration(d).as_slice(), expected);
+}
+
+#[test]
+fn duration_rejects_nanos_past_a_second() {
+    let secs = Vu64::new(7);
+    assert_eq!(
+        decode_duration(&raw(secs.as_slice(), 999_999_999)),
+        Ok((Duration::new(7, 999_999_999), secs.len() as usize + 5))
+    );
+    for nanos in [1_000_000_000, u32::MAX] {
+        let buf = raw(secs.as_slice(), nanos);
+        assert_eq!(decode_duration(&buf), Err(DecodeError::InvalidNanos(nanos)));
+        let err = (&buf[..]).read_duration().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(decode_error(&err), DecodeError::InvalidNanos(nanos));
+    }
+}
+
+#[test]
+fn duration_truncated() {
+    let buf = encode_duration(Duration::new(1 << 40, 123_456_789));
+    let buf = buf.as_slice();
+    for len in 0..buf.len() {
+        assert_eq!(
+            decode_duration(&buf[..len]),
+            Err(DecodeError::UnexpectedEof)
+        );
+        let err = (&buf[..len]).read_duration().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
+
+#[test]
+fn system_time_round_trips() {
+    for t in [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::new(1_700_000_000, 42),
+        UNIX_EPOCH - Duration::from_secs(1),
+        UNIX_EPOCH - Duration::new(1, 1),
+        UNIX_EPOCH - Duration::from_nanos(1),
+        UNIX_EPOCH - Duration::new(86_400 * 365 * 100, 500_000_000),
+        SystemTime::now(),
+    ] {
+        let mut buf = Vec::new();
+        buf.write_system_time(t).unwrap();
+        let mut reader = &buf[..];
+        assert_eq!(reader.read_system_time().unwrap(), t);
+        assert!(reader.is_empty());
+    }
+}
+
+#[test]
+fn system_time_floors_before_the_epoch() {
+    for (t, secs, nanos) in [
+        (UNIX_EPOCH + Duration::new(1, 1), 1, 1),
+        (UNIX_EPOCH - Duration::from_secs(1), -1, 0),
+        (UNIX_EPOCH - Duration::new(1, 1), -2, 999_999_999),
+        (UNIX_EPOCH - Duration::from_nanos(1), -1, 999_999_999),
+        (UNIX_EPOCH - Duration::from_millis(1500), -2, 500_000_000),
+    ] {
+        let mut buf = Vec::new();
+        buf.write_system_time(t).unwrap();
+        assert_eq!(
+            buf,
+            raw(Vi64::new(secs).as_slice(), nanos),
+            "{secs} {nanos}"
+        );
+    }
+}
+
+#[test]
+fn system_time_rejects_nanos_past_a_second() {
+    for nanos in [1_000_000_000, u32::MAX] {
+        let buf = raw(Vi64::new(-1).as_slice(), nanos);
+        let err = (&buf[..]).read_system_time().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(decode_error(&err), DecodeError::InvalidNanos(nanos));
+    }
+}
+
+#[cfg(any(feature = "async-futures", feature = "async-tokio"))]
+mod asynchronous {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use fastvlq::{AsyncReadVlqExt, AsyncWriteVlqExt, DecodeError, Vi64, Vu64, encode_duration};
+
+    use super::{decode_error, raw};
+
+    /// Run a future that never waits, as reads from slices and writes to vectors don't.
+    fn ready<F: Future>(future: F) -> F::Output {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("in-memory I/O never waits"),
+        }
+    }
+
+    #[test]
+    fn matches_sync() {
+        let d = Duration::new(1 << 40, 123_456_789);
+        let mut buf = Vec::new();
+        ready(AsyncWriteVlqExt::write_duration(&mut buf, d)).unwrap();
+        assert_eq!(buf, encode_duration(d).as_slice());
+        assert_eq!(ready((&mut &buf[..]).read_duration()).unwrap(), d);
+
+        let t = UNIX_EPOCH - Duration::new(1, 1);
+        let mut buf = Vec::new();
+        ready(AsyncWriteVlqExt::write_system_time(&mut buf, t)).unwrap();
+        assert_eq!(buf, raw(Vi64::new(-2).as_slice(), 999_999_999));
+        assert_eq!(ready((&mut &buf[..]).read_system_time()).unwrap(), t);
+    }
+
+    #[test]
+    fn rejects_nanos_past_a_second() {
+        let buf = raw(Vu64::new(7).as_slice(), 1_000_000_000);
+        let err = ready((&mut &buf[..]).read_duration()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::InvalidNanos(1_000_000_000));
+        let err = ready((&mut &buf[..]).read_system_time()).unwrap_err();
+        assert_eq!(decode_error(&err), DecodeError::InvalidNanos(1_000_000_000));
+    }
+}
@@ -0,0 +1,100 @@
+//! Check the standard traits every integer type implements against its value.
+
+use std::collections::HashSet;
+
+use fastvlq::{Vi32, Vi64, Vi128, Vu32, Vu64, Vu128};
+
+macro_rules! traits {
+    ($name:ident, $ty:ident($native:ty), $values:expr) => {
+        #[test]
+        fn $name() {
+            let values: Vec<$native> = $values.to_vec();
+
+            // Equal values hash the same, so duplicates collapse
+            let set: HashSet<$ty> = values.iter().chain(&values).map(|&n| $ty::new(n)).collect();
+            assert_eq!(set.len(), values.len());
+            for &n in &values {
+                assert!(set.contains(&$ty::new(n)));
+            }
+
+            // Sorting the encodings sorts the values
+            let mut sorted: Vec<$ty> = values.iter().rev().map(|&n| $ty::new(n)).collect();
+            sorted.sort();
+            let mut expected = values.clone();
+            expected.sort();
+            assert_eq!(sorted.iter().map($ty::get).collect::<Vec<_>>(), expected);
+            for pair in sorted.windows(2) {
+                assert!(pair[0] < pair[1]);
+            }
+
+            assert_eq!($ty::default().get(), 0);
+            for &n in &values {
+                let v = $ty::new(n);
+                assert_eq!(v.as_ref(), v.as_slice());
+                assert_eq!(n.to_string().parse::<$ty>(), Ok(v));
+                assert_eq!(v.to_string(), n.to_string());
+            }
+            assert!("".parse::<$ty>().is_err());
+            assert!("x".parse::<$ty>().is_err());
+        }
+    };
+}
+
+traits!(vu32, Vu32(u32), [0, 1, 0x7f, 0x80, 0x4080, u32::MAX]);
+traits!(
+    vu64,
+    Vu64(u64),
+    [0, 1, 0x80, 1 << 56, u64::MAX - 1, u64::MAX]
+);
+traits!(
+    vu128,
+    Vu128(u128),
+    [0, 1, 0x80, 1 << 64, 1 << 100, u128::MAX]
+);
+traits!(
+    vi32,
+    Vi32(i32),
+    [i32::MIN, -65, -64, -1, 0, 1, 64, i32::MAX]
+);
+traits!(
+    vi64,
+    Vi64(i64),
+    [i64::MIN, -65, -64, -1, 0, 1, 64, i64::MAX]
+);
+traits!(
+    vi128,
+    Vi128(i128),
+    [i128::MIN, i64::MIN as i128, -1, 0, 1, i128::MAX]
+);
+
+#[test]
+fn parse_out_of_range() {
+    assert!("-1".parse::<Vu32>().is_err());
+    assert!("-1".parse::<Vu64>().is_err());
+    assert!("-1".parse::<Vu128>().is_err());
+    assert!("4294967296".parse::<Vu32>().is_err());
+    assert!("18446744073709551616".parse::<Vu64>().is_err());
+    assert!("2147483648".parse::<Vi32>().is_err());
+    assert_eq!("-2147483648".parse::<Vi32>(), Ok(Vi32::new(i32::MIN)));
+}
+
+#[test]
+fn from_native() {
+    assert_eq!(Vu32::from(u16::MAX), Vu32::new(u16::MAX as u32));
+    assert_eq!(Vu64::from(u32::MAX), Vu64::new(u32::MAX as u64));
+    assert_eq!(Vu128::from(u64::MAX), Vu128::new(u64::MAX as u128));
+    assert_eq!(Vi32::from(i16::MIN), Vi32::new(i16::MIN as i32));
+    assert_eq!(Vi64::from(u32::MAX), Vi64::new(u32::MAX as i64));
+    assert_eq!(Vi128::from(i64::MIN), Vi128::new(i64::MIN as i128));
+
+    assert_eq!(Vu64::try_from(5i64), Ok(Vu64::new(5)));
+    assert!(Vu64::try_from(-1i64).is_err());
+    assert!(Vu64::try_from(i64::MIN).is_err());
+    assert!(Vu32::try_from(-1i32).is_err());
+    assert!(Vu32::try_from(u32::MAX as u64 + 1).is_err());
+    assert!(Vu128::try_from(-1i128).is_err());
+    assert!(Vi32::try_from(u32::MAX).is_err());
+    assert!(Vi64::try_from(u64::MAX).is_err());
+    assert!(Vi128::try_from(u128::MAX).is_err());
+    assert_eq!(Vi64::try_from(i64::MAX as u64), Ok(Vi64::new(i64::MAX)));
+}
@@ -0,0 +1,133 @@
+//! Round-trip `Vu128` and `Vu128V2` around the `Vu128` extended forms.
+
+use fastvlq::{DecodeError, ReadVlqExt, Vu128, Vu128V2, vu128_v1_to_v2};
+use proptest::prelude::*;
+
+/// First value of the `len`-byte class, matching the crate's `offset!`.
+fn offset(len: u32) -> u128 {
+    // The extended classes are spaced one bit wider than the standard ones
+    (1..len)
+        .map(|k| {
+            if k < 9 {
+                1u128 << (7 * k)
+            } else {
+                1u128 << (7 * k + 1)
+            }
+        })
+        .sum()
+}
+
+fn round_trip(n: u128) {
+    let v1 = Vu128::new(n);
+    assert_eq!(v1.get(), n, "{n:#x}");
+    assert_eq!(v1.len(), Vu128::encoded_len(n), "{n:#x}");
+    assert_eq!(
+        Vu128::from_bytes(v1.as_slice()).unwrap(),
+        (v1, v1.len() as usize)
+    );
+    assert_eq!((&mut v1.as_slice()).read_vu128().unwrap(), n, "{n:#x}");
+
+    let v2 = Vu128V2::new(n);
+    assert_eq!(v2.get(), n, "{n:#x}");
+    assert_eq!(v2.len(), Vu128V2::encoded_len(n), "{n:#x}");
+    assert_eq!((&mut v2.as_slice()).read_vu128_v2().unwrap(), n, "{n:#x}");
+    let (from_bytes, len) = Vu128V2::from_bytes(&[v2.as_slice(), &[0xff]].concat()).unwrap();
+    assert_eq!((from_bytes.get(), len), (n, v2.len() as usize), "{n:#x}");
+    assert_eq!(
+        Vu128V2::from_bytes(&v2.as_slice()[..len - 1]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+
+    let (converted, len) = vu128_v1_to_v2(v1.as_slice()).unwrap();
+    assert_eq!(converted.as_slice(), v2.as_slice(), "{n:#x}");
+    assert_eq!(len, v1.len() as usize);
+}
+
+#[test]
+fn nine_byte_lower_bound() {
+    // Values below this have no 9-byte form, and once took a 9-byte encoding that
+    // decoded to a different value
+    let nine_byte_min = offset(9) + (1 << 63);
+    assert_eq!(Vu128::encoded_len(nine_byte_min - 1), 18);
+    assert_eq!(Vu128::encoded_len(nine_byte_min), 9);
+    round_trip(nine_byte_min - 1);
+    round_trip(nine_byte_min);
+}
+
+#[test]
+fn ten_byte_upper_half() {
+    // The 10-byte class holds 70 bits, one less than the gap to `offset(11)`
+    let n = offset(10) + (1 << 70);
+    assert_eq!(Vu128::encoded_len(n - 1), 10);
+    assert_eq!(Vu128::encoded_len(n), 18);
+    round_trip(n - 1);
+    round_trip(n);
+}
+
+#[test]
+fn every_extended_class_boundary() {
+    for len in 10..=16 {
+        let start = offset(len);
+        let half = start + (1 << (7 * len));
+        for n in [start - 1, start, half - 1, half, offset(len + 1) - 1] {
+            round_trip(n);
+        }
+        assert_eq!(Vu128::encoded_len(half - 1), len as u8);
+        assert_eq!(Vu128::encoded_len(half), 18);
+    }
+    for n in [offset(17) - 1, offset(17), u128::MAX] {
+        round_trip(n);
+    }
+}
+
+#[test]
+fn v2_from_bytes_rejects_overflow() {
+    let max = Vu128V2::new(u128::MAX);
+    assert_eq!(max.len(), 17);
+    // Any larger 17-byte payload overflows once offset
+    let mut over = max.bytes();
+    let last = over.iter().rposition(|&b| b != 0xff).unwrap();
+    over[last] += 1;
+    over[last + 1..].fill(0);
+    assert_eq!(
+        Vu128V2::from_bytes(&over).unwrap_err(),
+        DecodeError::Overflow
+    );
+    // The smallest 17-byte form sits just past the 16-byte class
+    let (min, len) = Vu128V2::from_bytes(&[0x00; 17]).unwrap();
+    assert_eq!(len, 17);
+    assert_eq!(Vu128V2::encoded_len(min.get()), 17);
+    assert_eq!(Vu128V2::encoded_len(min.get() - 1), 16);
+
+    assert_eq!(
+        Vu128V2::from_bytes(&[]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vu128V2::from_bytes(&[0x01]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+}
+
+proptest! {
+    #[test]
+    fn round_trips(n: u128) {
+        round_trip(n);
+    }
+
+    #[test]
+    fn round_trips_by_width(bits in 0u32..=128, n: u128) {
+        // Uniform `u128`s are almost all 18 bytes long; spread them over every width
+        round_trip(n.checked_shr(bits).unwrap_or(0));
+    }
+
+    #[test]
+    fn v1_to_v2_ignores_trailing_bytes(n: u128, tail: Vec<u8>) {
+        let mut buf = Vu128::new(n).as_slice().to_vec();
+        let len = buf.len();
+        buf.extend(tail);
+        let (v2, consumed) = vu128_v1_to_v2(&buf).unwrap();
+        prop_assert_eq!(v2.get(), n);
+        prop_assert_eq!(consumed, len);
+    }
+}
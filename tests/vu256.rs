@@ -0,0 +1,214 @@
+//! Round-trip `Vu256` and `Vi256`, and check the forms they share with `Vu128`.
+
+use fastvlq::{DecodeError, ReadVlqExt, Vi128, Vi256, Vu128, Vu256, WriteVlqExt};
+use proptest::prelude::*;
+
+fn widen(n: u128) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[16..].copy_from_slice(&n.to_be_bytes());
+    out
+}
+
+fn round_trip(n: [u8; 32]) -> Vu256 {
+    let vlq = Vu256::new(n);
+    assert_eq!(vlq.get(), n);
+    assert_eq!(vlq.len(), Vu256::encoded_len(n));
+    let mut buf = Vec::new();
+    buf.write_vu256(n).unwrap();
+    assert_eq!(buf, vlq.as_slice());
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_vu256().unwrap(), n);
+    assert!(reader.is_empty());
+    let (from_bytes, len) = Vu256::from_bytes(&[&buf[..], &[0xff]].concat()).unwrap();
+    assert_eq!((from_bytes.get(), len), (n, buf.len()));
+    let prefix = [buf[0], *buf.get(1).unwrap_or(&0), *buf.get(2).unwrap_or(&0)];
+    assert_eq!(
+        Vu256::len_from_prefix(prefix[0], prefix[1], prefix[2]),
+        Ok(vlq.len())
+    );
+    vlq
+}
+
+fn round_trip_signed(n: [u8; 32]) -> Vi256 {
+    let vlq = Vi256::new(n);
+    assert_eq!(vlq.get(), n);
+    let mut buf = Vec::new();
+    buf.write_vi256(n).unwrap();
+    assert_eq!(buf, vlq.as_slice());
+    assert_eq!((&buf[..]).read_vi256().unwrap(), n);
+    assert_eq!(
+        Vi256::from_bytes(&buf).map(|(n, len)| (n.get(), len)),
+        Ok((n, buf.len()))
+    );
+    vlq
+}
+
+fn read_error(buf: &[u8]) -> DecodeError {
+    let err = (&mut &buf[..]).read_vu256().unwrap_err();
+    *err.get_ref()
+        .unwrap()
+        .downcast_ref::<DecodeError>()
+        .unwrap()
+}
+
+#[test]
+fn short_long_boundary() {
+    // The largest value with a 16-byte `Vu128` form; the rest of the class takes the
+    // raw 18-byte form
+    let offset_16: u128 = (1..16)
+        .map(|k| {
+            if k < 9 {
+                1u128 << (7 * k)
+            } else {
+                1u128 << (7 * k + 1)
+            }
+        })
+        .sum();
+    let n = offset_16 + (1 << 112) - 1;
+    assert_eq!(Vu128::new(n).len(), 16);
+    assert_eq!(Vu128::new(n + 1).len(), 18);
+    assert_eq!(round_trip(widen(n + 1)).as_slice()[..3], [0x00, 0x00, 0x8f]);
+    let short = round_trip(widen(n));
+    assert_eq!(short.as_slice(), Vu128::new(n).as_slice());
+    assert!(short.len() <= 16);
+
+    // 16 magnitude bytes in the long form
+    let long = round_trip(widen(u128::MAX));
+    assert_eq!(long.as_slice()[..3], [0x00, 0x00, 0x90]);
+    assert_eq!(long.len(), 19);
+
+    // 17 magnitude bytes
+    let mut n = [0u8; 32];
+    n[15] = 1;
+    let long = round_trip(n);
+    assert_eq!(long.as_slice()[..3], [0x00, 0x00, 0x91]);
+    assert_eq!(long.len(), 20);
+}
+
+#[test]
+fn max() {
+    let vlq = round_trip([0xff; 32]);
+    assert_eq!(vlq.len() as usize, Vu256::MAX_LEN);
+    assert_eq!(vlq.as_slice()[..3], [0x00, 0x00, 0xa0]);
+}
+
+#[test]
+fn rejects_long_forms_past_32_bytes() {
+    for len in [33u8, 34, 0x7f] {
+        let mut buf = vec![0x00, 0x00, 0x80 | len];
+        buf.resize(3 + len as usize, 0xff);
+        assert_eq!(read_error(&buf), DecodeError::Overflow, "{len}");
+        assert_eq!(Vu256::from_bytes(&buf).unwrap_err(), DecodeError::Overflow);
+        assert_eq!(Vi256::from_bytes(&buf).unwrap_err(), DecodeError::Overflow);
+    }
+    // The length byte must carry the 1-byte prefix
+    assert_eq!(read_error(&[0x00, 0x00, 0x20]), DecodeError::Overflow);
+    assert_eq!(
+        Vu256::from_bytes(&[0x00, 0x00, 0x20]).unwrap_err(),
+        DecodeError::Overflow
+    );
+    let mut buf = vec![0x00, 0x00, 0xa0];
+    buf.extend([0xff; 32]);
+    assert_eq!((&buf[..]).read_vu256().unwrap(), [0xff; 32]);
+}
+
+#[test]
+fn len_from_prefix_never_exceeds_max_len() {
+    for third in 0..=u8::MAX {
+        match Vu256::len_from_prefix(0x00, 0x00, third) {
+            Ok(len) => {
+                assert!(len as usize <= Vu256::MAX_LEN, "{third:#x}");
+                assert_eq!(len, 3 + (third & 0x7f), "{third:#x}");
+            }
+            Err(e) => {
+                assert_eq!(e, DecodeError::Overflow);
+                assert!(!(0x80..=0xa0).contains(&third), "{third:#x}");
+            }
+        }
+        assert_eq!(
+            Vi256::len_from_prefix(0x00, 0x00, third),
+            Vu256::len_from_prefix(0x00, 0x00, third)
+        );
+    }
+    assert_eq!(Vu256::len_from_prefix(0x00, 0xff, 0xff), Ok(9));
+    assert_eq!(Vu256::len_from_prefix(0x80, 0x00, 0x00), Ok(1));
+}
+
+#[test]
+fn from_bytes_rejects_bad_input() {
+    let long = Vu256::new([0xff; 32]);
+    for len in 0..long.len() as usize {
+        assert_eq!(
+            Vu256::from_bytes(&long.as_slice()[..len]).unwrap_err(),
+            DecodeError::UnexpectedEof,
+            "{len}"
+        );
+    }
+    assert_eq!(
+        Vu256::from_bytes(&[0x00]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+    assert_eq!(
+        Vu256::from_bytes(&[0x40]).unwrap_err(),
+        DecodeError::UnexpectedEof
+    );
+
+    // The long form of a value with a short form, and one with a leading zero byte
+    assert_eq!(
+        Vu256::from_bytes(&[0x00, 0x00, 0x81, 0x05]).unwrap_err(),
+        DecodeError::NonCanonical
+    );
+    let mut padded = vec![0x00, 0x00, 0xa0, 0x00];
+    padded.extend([0xff; 31]);
+    assert_eq!(
+        Vu256::from_bytes(&padded).unwrap_err(),
+        DecodeError::NonCanonical
+    );
+    // The reader takes them as they are
+    assert_eq!((&padded[..]).read_vu256().unwrap()[1..], [0xff; 31]);
+}
+
+#[test]
+fn signed_extremes() {
+    let mut min = [0u8; 32];
+    min[0] = 0x80;
+    let mut max = [0xff; 32];
+    max[0] = 0x7f;
+    for n in [[0; 32], [0xff; 32], min, max] {
+        round_trip_signed(n);
+    }
+    assert_eq!(round_trip_signed(min).len() as usize, Vi256::MAX_LEN);
+    assert_eq!(round_trip_signed(max).len() as usize, Vi256::MAX_LEN);
+}
+
+proptest! {
+    #[test]
+    fn shares_short_vu128_forms(n: u128, shift in 0u32..128) {
+        let n = n >> shift;
+        let v128 = Vu128::new(n);
+        let v256 = round_trip(widen(n));
+        if v128.len() <= 16 {
+            prop_assert_eq!(v256.as_slice(), v128.as_slice());
+        } else {
+            prop_assert_eq!(&v256.as_slice()[..2], &[0x00, 0x00]);
+        }
+    }
+
+    #[test]
+    fn shares_short_vi128_forms(n: i128, shift in 0u32..128) {
+        let n = n >> shift;
+        let v128 = Vi128::new(n);
+        let v256 = round_trip_signed(Vi256::from(n).get());
+        if v128.len() <= 16 {
+            prop_assert_eq!(v256.as_slice(), v128.as_slice());
+        }
+    }
+
+    #[test]
+    fn round_trips(n: [u8; 32], zeros in 0usize..=32) {
+        let mut n = n;
+        n[..zeros].fill(0);
+        round_trip(n);
+        round_trip_signed(n);
+    }
+}
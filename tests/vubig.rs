@@ -0,0 +1,119 @@
+//! Round-trip `VuBig` across the switch from the `Vu128` forms to the long form.
+
+use fastvlq::{DecodeError, ReadVlqExt, Vu128, VuBig, WriteVlqExt, decode_big};
+
+fn round_trip(magnitude: &[u8]) -> VuBig {
+    let n = VuBig::new(magnitude);
+    let trimmed = &magnitude[magnitude.iter().take_while(|&&b| b == 0).count()..];
+    assert_eq!(n.magnitude(), trimmed);
+    assert_eq!(
+        decode_big(n.as_slice()).unwrap(),
+        (trimmed.to_vec(), n.len())
+    );
+
+    let mut buf = Vec::new();
+    buf.write_vubig(magnitude).unwrap();
+    assert_eq!(buf, n.as_slice());
+    assert_eq!((&buf[..]).read_vubig(magnitude.len()).unwrap(), trimmed);
+    n
+}
+
+#[test]
+fn zero() {
+    for magnitude in [&[][..], &[0], &[0; 20]] {
+        assert_eq!(round_trip(magnitude).as_slice(), [0x80]);
+    }
+}
+
+#[test]
+fn shares_short_vu128_forms() {
+    for n in [1u128, 127, 128, u64::MAX as u128, 1 << 100] {
+        let vlq = Vu128::new(n);
+        assert!(vlq.len() <= 16);
+        assert_eq!(round_trip(&n.to_be_bytes()).as_slice(), vlq.as_slice());
+    }
+}
+
+#[test]
+fn sixteen_byte_magnitude() {
+    // `Vu128` needs the raw 18-byte form here, which `VuBig` replaces with the long form
+    let magnitude = [0xff; 16];
+    assert_eq!(Vu128::new(u128::MAX).len(), 18);
+    let n = round_trip(&magnitude);
+    assert_eq!(n.as_slice()[..3], [0x00, 0x00, 0x90]);
+    assert_eq!(n.len(), 3 + 16);
+}
+
+#[test]
+fn seventeen_byte_magnitude() {
+    let mut magnitude = [0u8; 17];
+    magnitude[0] = 1;
+    let n = round_trip(&magnitude);
+    assert_eq!(n.as_slice()[..3], [0x00, 0x00, 0x91]);
+    assert_eq!(n.len(), 3 + 17);
+
+    // Leading zeros do not count towards the length
+    let mut padded = vec![0; 4];
+    padded.extend_from_slice(&magnitude);
+    assert_eq!(round_trip(&padded).as_slice(), n.as_slice());
+}
+
+#[test]
+fn max_len_rejects_long_forms() {
+    let n = VuBig::new(&[0xff; 17]);
+    let err = (&mut n.as_slice()).read_vubig(16).unwrap_err();
+    assert_eq!(
+        *err.get_ref()
+            .unwrap()
+            .downcast_ref::<DecodeError>()
+            .unwrap(),
+        DecodeError::LengthLimitExceeded { len: 17, max: 16 }
+    );
+    assert!((&mut n.as_slice()).read_vubig(17).is_ok());
+}
+
+#[test]
+fn truncated_long_form() {
+    let n = VuBig::new(&[0xff; 17]);
+    let truncated = &n.as_slice()[..n.len() - 1];
+    assert_eq!(decode_big(truncated), Err(DecodeError::UnexpectedEof));
+}
+
+#[cfg(feature = "num-bigint")]
+mod bigint {
+    use fastvlq::{Vi128, VuBig};
+    use num_bigint::BigInt;
+
+    fn round_trip(n: BigInt) {
+        let encoded = VuBig::from_bigint(&n);
+        assert_eq!(encoded.to_bigint(), n);
+        if let Ok(small) = i128::try_from(&n) {
+            let vlq = Vi128::new(small);
+            if vlq.len() <= 16 {
+                assert_eq!(encoded.as_slice(), vlq.as_slice());
+            }
+        }
+    }
+
+    #[test]
+    fn signed() {
+        for n in [
+            0i128,
+            1,
+            -1,
+            63,
+            -64,
+            64,
+            -65,
+            i64::MIN as i128,
+            i128::MAX,
+            i128::MIN,
+        ] {
+            round_trip(BigInt::from(n));
+        }
+        let big = BigInt::from(1) << 200u32;
+        for n in [big.clone(), -big.clone(), big.clone() - 1, -big - 1] {
+            round_trip(n);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Check conversions between widths against encoding each value afresh.
+
+use fastvlq::{DecodeError, Vi32, Vi64, Vi128, Vu32, Vu64, Vu128};
+use proptest::prelude::*;
+
+/// First value of the 9-byte `Vu64` class.
+const NINE_BYTE_MIN: u64 =
+    (1 << 7) + (1 << 14) + (1 << 21) + (1 << 28) + (1 << 35) + (1 << 42) + (1 << 49) + (1 << 56);
+
+/// `Vu128`'s 9-byte class starts halfway through `Vu64`'s.
+const SHARED_NINE_BYTE_MIN: u64 = NINE_BYTE_MIN + (1 << 63);
+
+fn widen_vu32(n: u32) {
+    let v = Vu32::new(n);
+    let wide = Vu64::from(v);
+    assert_eq!(wide, Vu64::new(n as u64));
+    assert_eq!(wide.as_slice(), v.as_slice());
+    let wider = Vu128::from(v);
+    assert_eq!(wider, Vu128::new(n as u128));
+    assert_eq!(wider.as_slice(), v.as_slice());
+
+    assert_eq!(Vu32::try_from(wide), Ok(v));
+    assert_eq!(Vu32::try_from(wider), Ok(v));
+}
+
+fn widen_vu64(n: u64) {
+    let v = Vu64::new(n);
+    let wide = Vu128::from(v);
+    assert_eq!(wide, Vu128::new(n as u128), "{n:#x}");
+    // Only 9-byte values whose second byte is below 0x80 are re-encoded
+    if v.len() < 9 || v.bytes()[1] >= 0x80 {
+        assert_eq!(wide.as_slice(), v.as_slice(), "{n:#x}");
+    } else {
+        assert_eq!(wide.len(), 18, "{n:#x}");
+    }
+    assert_eq!(Vu64::try_from(wide), Ok(v), "{n:#x}");
+}
+
+fn widen_vi32(n: i32) {
+    let v = Vi32::new(n);
+    let wide = Vi64::from(v);
+    assert_eq!(wide, Vi64::new(n as i64));
+    assert_eq!(wide.as_slice(), v.as_slice());
+    let wider = Vi128::from(v);
+    assert_eq!(wider, Vi128::new(n as i128));
+    assert_eq!(wider.as_slice(), v.as_slice());
+
+    assert_eq!(Vi32::try_from(wide), Ok(v));
+    assert_eq!(Vi32::try_from(wider), Ok(v));
+}
+
+fn widen_vi64(n: i64) {
+    let v = Vi64::new(n);
+    let wide = Vi128::from(v);
+    assert_eq!(wide, Vi128::new(n as i128), "{n}");
+    assert_eq!(Vi64::try_from(wide), Ok(v), "{n}");
+}
+
+#[test]
+fn vu32_edges() {
+    for n in [0, 0x7f, 0x80, 0x407f, 0x4080, u32::MAX] {
+        widen_vu32(n);
+    }
+}
+
+#[test]
+fn vu64_nine_byte_values() {
+    for n in [
+        NINE_BYTE_MIN - 1,
+        NINE_BYTE_MIN,
+        SHARED_NINE_BYTE_MIN - 1,
+        SHARED_NINE_BYTE_MIN,
+        u64::MAX,
+    ] {
+        widen_vu64(n);
+    }
+    assert_eq!(Vu64::new(NINE_BYTE_MIN - 1).len(), 8);
+    assert_eq!(Vu64::new(NINE_BYTE_MIN).bytes()[1], 0x00);
+    assert_eq!(Vu64::new(SHARED_NINE_BYTE_MIN - 1).bytes()[1], 0x7f);
+    assert_eq!(Vu64::new(SHARED_NINE_BYTE_MIN).bytes()[1], 0x80);
+    assert_eq!(Vu128::from(Vu64::new(SHARED_NINE_BYTE_MIN)).len(), 9);
+}
+
+#[test]
+fn signed_edges() {
+    for n in [0, -1, 1, -64, 64, i32::MIN, i32::MAX] {
+        widen_vi32(n);
+    }
+    for n in [i64::MIN, i64::MIN + 1, i64::MAX, i32::MIN as i64 - 1] {
+        widen_vi64(n);
+    }
+}
+
+#[test]
+fn narrowing_out_of_range() {
+    let over = u32::MAX as u64 + 1;
+    assert_eq!(Vu32::try_from(Vu64::new(over)), Err(DecodeError::Overflow));
+    assert_eq!(
+        Vu32::try_from(Vu128::new(over as u128)),
+        Err(DecodeError::Overflow)
+    );
+    let over = u64::MAX as u128 + 1;
+    assert_eq!(Vu64::try_from(Vu128::new(over)), Err(DecodeError::Overflow));
+    assert_eq!(Vu32::try_from(Vu128::new(over)), Err(DecodeError::Overflow));
+    assert_eq!(
+        Vu64::try_from(Vu128::new(u128::MAX)),
+        Err(DecodeError::Overflow)
+    );
+
+    for n in [i32::MIN as i64 - 1, i32::MAX as i64 + 1, i64::MIN, i64::MAX] {
+        assert_eq!(Vi32::try_from(Vi64::new(n)), Err(DecodeError::Overflow));
+        assert_eq!(
+            Vi32::try_from(Vi128::new(n as i128)),
+            Err(DecodeError::Overflow)
+        );
+    }
+    for n in [
+        i64::MIN as i128 - 1,
+        i64::MAX as i128 + 1,
+        i128::MIN,
+        i128::MAX,
+    ] {
+        assert_eq!(Vi64::try_from(Vi128::new(n)), Err(DecodeError::Overflow));
+    }
+}
+
+proptest! {
+    #[test]
+    fn vu32_widens(n: u32) {
+        widen_vu32(n);
+    }
+
+    #[test]
+    fn vu64_widens(bits in 0u32..64, n: u64) {
+        // Uniform `u64`s are almost all 9 bytes long; spread them over every width
+        widen_vu64(n >> bits);
+        widen_vu64(n);
+    }
+
+    #[test]
+    fn signed_widens(a: i32, b: i64) {
+        widen_vi32(a);
+        widen_vi64(b);
+    }
+
+    #[test]
+    fn narrowing_matches_the_value(bits in 0u32..=128, n: u128) {
+        let n = n.checked_shr(bits).unwrap_or(0);
+        let v = Vu128::new(n);
+        assert_eq!(Vu64::try_from(v).map(|v| v.get() as u128), u64::try_from(n).map(u128::from).map_err(|_| DecodeError::Overflow));
+        assert_eq!(Vu32::try_from(v).map(|v| v.get() as u128), u32::try_from(n).map(u128::from).map_err(|_| DecodeError::Overflow));
+    }
+}